@@ -1,9 +1,7 @@
-use crate::{DayCountFraction, DayCounter};
-
-#[cfg(not(feature = "hifitime"))]
-use chrono::NaiveDate;
-#[cfg(feature = "hifitime")]
-use hifitime::{Epoch, Unit};
+use crate::{
+    actual_days, actual_fraction, date_from_days, estimate_days, DayCountDate, DayCountFraction,
+    DayCounter,
+};
 
 /// Actual/360
 ///
@@ -18,16 +16,16 @@ use hifitime::{Epoch, Unit};
 pub struct Actual360;
 
 impl DayCounter for Actual360 {
-    #[cfg(not(feature = "hifitime"))]
-    fn day_count_fraction(&self, start: &NaiveDate, end: &NaiveDate) -> DayCountFraction<Self> {
-        DayCountFraction::new((*end - *start).num_days() as f64 / 360.0)
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        actual_fraction(start, end, 0, (360, 1))
     }
-    #[cfg(feature = "hifitime")]
-    fn day_count_fraction(&self, start: &Epoch, end: &Epoch) -> DayCountFraction<Self>
-    where
-        Self: Sized,
-    {
-        DayCountFraction::new((*end - *start).to_unit(Unit::Day) / 360.0)
+
+    fn days_in_basis<T: DayCountDate>(&self, _start: &T, _end: &T) -> Option<f64> {
+        Some(360.0)
+    }
+
+    fn date_from_fraction<T: DayCountDate>(&self, start: &T, fraction: f64) -> Option<T> {
+        date_from_days(self, start, fraction, estimate_days(fraction, 360.0), 0)
     }
 }
 
@@ -46,19 +44,25 @@ impl std::fmt::Display for Actual360 {
 /// $$
 ///
 /// where $d_2 - d_1$ is the number of days between the two dates.
-
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
 pub struct Actual360Inc;
 
 impl DayCounter for Actual360Inc {
-    #[cfg(not(feature = "hifitime"))]
-    fn day_count_fraction(&self, start: &NaiveDate, end: &NaiveDate) -> DayCountFraction<Self> {
-        DayCountFraction::new(((*end - *start).num_days() + 1) as f64 / 360.0)
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        actual_fraction(start, end, 1, (360, 1))
     }
-    #[cfg(feature = "hifitime")]
-    fn day_count_fraction(&self, start: &Epoch, end: &Epoch) -> DayCountFraction<Self> {
-        DayCountFraction::new(((*end - *start).to_unit(Unit::Day) + 1.0) / 360.0)
+
+    fn day_count<T: DayCountDate>(&self, start: &T, end: &T) -> i64 {
+        actual_days(start, end) + 1
+    }
+
+    fn days_in_basis<T: DayCountDate>(&self, _start: &T, _end: &T) -> Option<f64> {
+        Some(360.0)
+    }
+
+    fn date_from_fraction<T: DayCountDate>(&self, start: &T, fraction: f64) -> Option<T> {
+        date_from_days(self, start, fraction, estimate_days(fraction, 360.0) - 1, 0)
     }
 }
 
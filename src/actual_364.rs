@@ -1,9 +1,6 @@
-use crate::{DayCountFraction, DayCounter};
-
-#[cfg(not(feature = "hifitime"))]
-use chrono::NaiveDate;
-#[cfg(feature = "hifitime")]
-use hifitime::{Epoch, Unit};
+use crate::{
+    actual_fraction, date_from_days, estimate_days, DayCountDate, DayCountFraction, DayCounter,
+};
 
 /// Actual/364
 ///
@@ -17,16 +14,16 @@ use hifitime::{Epoch, Unit};
 pub struct Actual364;
 
 impl DayCounter for Actual364 {
-    #[cfg(not(feature = "hifitime"))]
-    fn day_count_fraction(&self, start: &NaiveDate, end: &NaiveDate) -> DayCountFraction<Self> {
-        DayCountFraction::new((*end - *start).num_days() as f64 / 364.0)
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        actual_fraction(start, end, 0, (364, 1))
+    }
+
+    fn days_in_basis<T: DayCountDate>(&self, _start: &T, _end: &T) -> Option<f64> {
+        Some(364.0)
     }
-    #[cfg(feature = "hifitime")]
-    fn day_count_fraction(&self, start: &Epoch, end: &Epoch) -> DayCountFraction<Self>
-    where
-        Self: Sized,
-    {
-        DayCountFraction::new((*end - *start).to_unit(Unit::Day) / 364.0)
+
+    fn date_from_fraction<T: DayCountDate>(&self, start: &T, fraction: f64) -> Option<T> {
+        date_from_days(self, start, fraction, estimate_days(fraction, 364.0), 0)
     }
 }
 
@@ -1,9 +1,7 @@
-use crate::{is_feb29_between_exc_inc, DayCountFraction, DayCounter};
-
-#[cfg(not(feature = "hifitime"))]
-use chrono::NaiveDate;
-#[cfg(feature = "hifitime")]
-use hifitime::{Epoch, Unit};
+use crate::{
+    actual_days, actual_fraction, add_months, date_from_days, estimate_days,
+    is_feb29_between_exc_inc, CivilDate, DayCountDate, DayCountFraction, DayCounter, Frequency,
+};
 
 /// Actual/365 (Fixed)
 ///
@@ -12,19 +10,21 @@ use hifitime::{Epoch, Unit};
 /// $$
 ///
 /// where $d_2 - d_1$ is the number of days between the two dates.
-
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
 pub struct Actual365Fixed;
 
 impl DayCounter for Actual365Fixed {
-    #[cfg(not(feature = "hifitime"))]
-    fn day_count_fraction(&self, start: &NaiveDate, end: &NaiveDate) -> DayCountFraction<Self> {
-        DayCountFraction::new((*end - *start).num_days() as f64 / 365.0)
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        actual_fraction(start, end, 0, (365, 1))
     }
-    #[cfg(feature = "hifitime")]
-    fn day_count_fraction(&self, start: &Epoch, end: &Epoch) -> DayCountFraction<Self> {
-        DayCountFraction::new((*end - *start).to_unit(Unit::Day) / 365.0)
+
+    fn days_in_basis<T: DayCountDate>(&self, _start: &T, _end: &T) -> Option<f64> {
+        Some(365.0)
+    }
+
+    fn date_from_fraction<T: DayCountDate>(&self, start: &T, fraction: f64) -> Option<T> {
+        date_from_days(self, start, fraction, estimate_days(fraction, 365.0), 0)
     }
 }
 
@@ -43,38 +43,295 @@ impl std::fmt::Display for Actual365Fixed {
 /// where $d_2 - d_1$ is the number of days between the two dates
 /// and $\text{Denominator}$ is 366 if February 29th is between
 /// $d_1$ (exclusive) and $d_2$ (inclusive), and 365 otherwise.
-
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
 pub struct Actual365A;
 
-impl DayCounter for Actual365A {
-    #[cfg(not(feature = "hifitime"))]
-    fn day_count_fraction(
-        &self,
-        start: &chrono::NaiveDate,
-        end: &chrono::NaiveDate,
-    ) -> DayCountFraction<Self> {
-        let denominator = if is_feb29_between_exc_inc(*start, *end) {
+impl Actual365A {
+    fn denominator<T: DayCountDate>(start: &T, end: &T) -> i64 {
+        if is_feb29_between_exc_inc(*start, *end) {
             366
         } else {
             365
-        };
-        DayCountFraction::new((*end - *start).num_days() as f64 / f64::from(denominator))
+        }
+    }
+}
+
+impl DayCounter for Actual365A {
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        actual_fraction(start, end, 0, (Self::denominator(start, end), 1))
+    }
+
+    fn days_in_basis<T: DayCountDate>(&self, start: &T, end: &T) -> Option<f64> {
+        Some(Self::denominator(start, end) as f64)
+    }
+}
+
+impl std::fmt::Display for Actual365A {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Actual/365 (A)")
+    }
+}
+
+/// Actual/365L (ICMA)
+///
+/// $$
+/// \frac{d_2 - d_1}{\text{Denominator}}
+/// $$
+///
+/// where $d_2 - d_1$ is the number of days between the two dates.
+///
+/// For annual coupons, $\text{Denominator}$ is 366 if February 29th is
+/// between $d_1$ (exclusive) and $d_2$ (inclusive), and 365 otherwise.
+/// For all other coupon frequencies, $\text{Denominator}$ is 366 if $d_2$
+/// falls in a leap year, and 365 otherwise.
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(tag = "convention", rename = "Actual/365L")
+)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Actual365L {
+    /// Coupon frequency of the instrument.
+    pub frequency: Frequency,
+}
+
+impl Actual365L {
+    /// Create a new [`Actual365L`] with a given coupon frequency.
+    #[must_use]
+    pub const fn new(frequency: Frequency) -> Self {
+        Self { frequency }
     }
-    #[cfg(feature = "hifitime")]
-    fn day_count_fraction(&self, start: &Epoch, end: &Epoch) -> DayCountFraction<Self> {
-        let denominator = if is_feb29_between_exc_inc(*start, *end) {
+}
+
+impl Actual365L {
+    fn denominator<T: DayCountDate>(&self, start: &T, end: &T) -> i64 {
+        let (start, end) = if start > end {
+            (end, start)
+        } else {
+            (start, end)
+        };
+        let is_leap = if self.frequency == Frequency::Annual {
+            is_feb29_between_exc_inc(*start, *end)
+        } else {
+            end.is_leap_year()
+        };
+        if is_leap {
             366
         } else {
             365
-        };
-        DayCountFraction::new((*end - *start).to_unit(Unit::Day) / f64::from(denominator))
+        }
     }
 }
 
-impl std::fmt::Display for Actual365A {
+impl DayCounter for Actual365L {
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        actual_fraction(start, end, 0, (self.denominator(start, end), 1))
+    }
+
+    fn days_in_basis<T: DayCountDate>(&self, start: &T, end: &T) -> Option<f64> {
+        Some(self.denominator(start, end) as f64)
+    }
+}
+
+impl std::fmt::Display for Actual365L {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Actual/365 (A)")
+        write!(f, "Actual/365L")
+    }
+}
+
+/// Actual/365 (Canadian Bond)
+///
+/// Let $F$ be the coupon frequency (number of coupons per year) and let
+/// $R_2 - R_1$ be the number of days in the reference coupon period. If
+/// $d_2 - d_1 < \frac{365}{F}$, then the day count fraction is
+///
+/// $$
+/// \frac{d_2 - d_1}{365}
+/// $$
+///
+/// and otherwise
+///
+/// $$
+/// \frac{1}{F} - \frac{(R_2 - R_1) - (d_2 - d_1)}{365}
+/// $$
+///
+/// where $d_2 - d_1$ is the number of days between the two dates.
+///
+/// The [`Default`] reference period is the annual coupon period starting on
+/// 1970-01-01.
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(tag = "convention", rename = "Actual/365 (Canadian Bond)")
+)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Actual365Canadian {
+    reference_start: CivilDate,
+    reference_end: CivilDate,
+    frequency: Frequency,
+}
+
+impl Actual365Canadian {
+    /// Create a new [`Actual365Canadian`] with a given reference period and coupon frequency.
+    ///
+    /// Returns [`None`] unless the reference period starts before it ends.
+    #[must_use]
+    pub fn new<T: DayCountDate>(
+        reference_start: T,
+        reference_end: T,
+        frequency: Frequency,
+    ) -> Option<Self> {
+        (reference_start < reference_end).then(|| Self {
+            reference_start: reference_start.to_civil(),
+            reference_end: reference_end.to_civil(),
+            frequency,
+        })
+    }
+
+    /// Start of the coupon period the accrual period refers to.
+    #[must_use]
+    pub const fn reference_start(&self) -> CivilDate {
+        self.reference_start
+    }
+
+    /// End of the coupon period the accrual period refers to.
+    #[must_use]
+    pub const fn reference_end(&self) -> CivilDate {
+        self.reference_end
+    }
+
+    /// Coupon frequency of the instrument.
+    #[must_use]
+    pub const fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+}
+
+impl Default for Actual365Canadian {
+    fn default() -> Self {
+        let reference_start = CivilDate::default();
+        Self {
+            reference_start,
+            reference_end: add_months(reference_start, 12),
+            frequency: Frequency::Annual,
+        }
+    }
+}
+
+impl DayCounter for Actual365Canadian {
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        if start > end {
+            return -self.day_count_fraction(end, start);
+        }
+
+        let frequency = i64::from(self.frequency.periods_per_year());
+        let days = actual_fraction(start, end, 0, (365, 1));
+
+        if days.get_fraction() < 1.0 / frequency as f64 {
+            days
+        } else {
+            let reference_days = actual_days(&self.reference_start, &self.reference_end);
+            DayCountFraction::from_ratio(365 - frequency * reference_days, 365 * frequency) + days
+        }
+    }
+
+    fn days_in_basis<T: DayCountDate>(&self, start: &T, end: &T) -> Option<f64> {
+        let frequency = f64::from(self.frequency.periods_per_year());
+        (start.days_until(end).abs() < 365.0 / frequency).then_some(365.0)
+    }
+
+    /// The fraction drops when the period reaches a full coupon period, so
+    /// the date is computed on each side of that threshold.
+    #[allow(clippy::cast_precision_loss)]
+    fn date_from_fraction<T: DayCountDate>(&self, start: &T, fraction: f64) -> Option<T> {
+        let frequency = i64::from(self.frequency.periods_per_year());
+        let threshold = (365 + frequency - 1) / frequency;
+
+        let days = estimate_days(fraction, 365.0);
+        if days < threshold {
+            return date_from_days(self, start, fraction, days, 0);
+        }
+
+        // Past the threshold, the fraction is 1/F - (R - days)/365.
+        let reference_days = actual_days(&self.reference_start, &self.reference_end);
+        let days = reference_days + estimate_days(fraction - 1.0 / frequency as f64, 365.0);
+        date_from_days(self, start, fraction, days, threshold)
+    }
+}
+
+impl std::fmt::Display for Actual365Canadian {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Actual/365 (Canadian Bond)")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Actual365Canadian, Actual365L, DayCountDate, DayCountFraction, DayCounter, Frequency,
+    };
+
+    fn actual_365l<T: DayCountDate>() {
+        // Annual coupons use 366 days when February 29th is in the period.
+        let dc = Actual365L::new(Frequency::Annual);
+        let (day1, day2) = (T::from_ymd(2023, 3, 1), T::from_ymd(2024, 3, 1));
+        assert_eq!(
+            dc.day_count_fraction(&day1, &day2),
+            DayCountFraction::from_ratio(366, 366)
+        );
+        assert_eq!(dc.days_in_basis(&day1, &day2), Some(366.0));
+        assert_eq!(
+            dc.day_count_fraction(&day2, &day1),
+            DayCountFraction::from_ratio(-366, 366)
+        );
+        assert_eq!(dc.days_in_basis(&day2, &day1), Some(366.0));
+
+        // Other frequencies use 366 days when the end date is in a leap year.
+        let dc = Actual365L::new(Frequency::SemiAnnual);
+        let (day1, day2) = (T::from_ymd(2023, 7, 15), T::from_ymd(2024, 1, 15));
+        assert_eq!(
+            dc.day_count_fraction(&day1, &day2),
+            DayCountFraction::from_ratio(184, 366)
+        );
+        assert_eq!(dc.days_in_basis(&day1, &day2), Some(366.0));
+        assert_eq!(
+            dc.day_count_fraction(&day2, &day1),
+            DayCountFraction::from_ratio(-184, 366)
+        );
+    }
+
+    fn actual_365_canadian<T: DayCountDate>() {
+        let reference_start = T::from_ymd(2023, 6, 1);
+        let reference_end = T::from_ymd(2023, 12, 1);
+        let dc =
+            Actual365Canadian::new(reference_start, reference_end, Frequency::SemiAnnual).unwrap();
+
+        let yf = dc.day_count_fraction(&reference_start, &T::from_ymd(2023, 11, 30));
+        assert!((yf.get_fraction() - 182.0 / 365.0).abs() < 1e-12);
+
+        let yf = dc.day_count_fraction(&reference_start, &reference_end);
+        assert!((yf.get_fraction() - 0.5).abs() < 1e-12);
+    }
+
+    fn actual_365_canadian_reference_period<T: DayCountDate>() {
+        let day = T::from_ymd(2024, 1, 1);
+        assert!(Actual365Canadian::new(day, day, Frequency::Annual).is_none());
+        assert!(
+            Actual365Canadian::new(T::from_ymd(2024, 7, 1), day, Frequency::SemiAnnual).is_none()
+        );
+
+        let dc = Actual365Canadian::default();
+        assert!(dc.reference_start() < dc.reference_end());
+        let yf = dc.day_count_fraction(&T::from_ymd(2023, 1, 1), &day);
+        assert_eq!(yf, DayCountFraction::from_ratio(1, 1));
+    }
+
+    test_date_backends!(
+        actual_365l,
+        actual_365_canadian,
+        actual_365_canadian_reference_period
+    );
+}
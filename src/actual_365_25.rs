@@ -1,9 +1,7 @@
-use crate::{DayCountFraction, DayCounter};
-
-#[cfg(not(feature = "hifitime"))]
-use chrono::NaiveDate;
-#[cfg(feature = "hifitime")]
-use hifitime::{Epoch, Unit};
+use crate::{
+    actual_days, actual_fraction, date_from_days, estimate_days, DayCountDate, DayCountFraction,
+    DayCounter,
+};
 
 /// Actual/365.25
 ///
@@ -18,13 +16,16 @@ use hifitime::{Epoch, Unit};
 pub struct Actual36525;
 
 impl DayCounter for Actual36525 {
-    #[cfg(not(feature = "hifitime"))]
-    fn day_count_fraction(&self, start: &NaiveDate, end: &NaiveDate) -> DayCountFraction<Self> {
-        DayCountFraction::new((*end - *start).num_days() as f64 / 365.25)
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        actual_fraction(start, end, 0, (1461, 4))
     }
-    #[cfg(feature = "hifitime")]
-    fn day_count_fraction(&self, start: &Epoch, end: &Epoch) -> DayCountFraction<Self> {
-        DayCountFraction::new((*end - *start).to_unit(Unit::Day) / 365.25)
+
+    fn days_in_basis<T: DayCountDate>(&self, _start: &T, _end: &T) -> Option<f64> {
+        Some(365.25)
+    }
+
+    fn date_from_fraction<T: DayCountDate>(&self, start: &T, fraction: f64) -> Option<T> {
+        date_from_days(self, start, fraction, estimate_days(fraction, 365.25), 0)
     }
 }
 
@@ -43,19 +44,31 @@ impl std::fmt::Display for Actual36525 {
 /// $$
 ///
 /// where $d_2 - d_1$ is the number of days between the two dates.
-
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
 pub struct Actual36525Inc;
 
 impl DayCounter for Actual36525Inc {
-    #[cfg(not(feature = "hifitime"))]
-    fn day_count_fraction(&self, start: &NaiveDate, end: &NaiveDate) -> DayCountFraction<Self> {
-        DayCountFraction::new(((*end - *start).num_days() + 1) as f64 / 365.25)
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        actual_fraction(start, end, 1, (1461, 4))
     }
-    #[cfg(feature = "hifitime")]
-    fn day_count_fraction(&self, start: &Epoch, end: &Epoch) -> DayCountFraction<Self> {
-        DayCountFraction::new(((*end - *start).to_unit(Unit::Day) + 1.0) / 365.25)
+
+    fn day_count<T: DayCountDate>(&self, start: &T, end: &T) -> i64 {
+        actual_days(start, end) + 1
+    }
+
+    fn days_in_basis<T: DayCountDate>(&self, _start: &T, _end: &T) -> Option<f64> {
+        Some(365.25)
+    }
+
+    fn date_from_fraction<T: DayCountDate>(&self, start: &T, fraction: f64) -> Option<T> {
+        date_from_days(
+            self,
+            start,
+            fraction,
+            estimate_days(fraction, 365.25) - 1,
+            0,
+        )
     }
 }
 
@@ -0,0 +1,419 @@
+use crate::{
+    actual_days, actual_fraction, add_months, get_last_day_of_month, is_feb29_between_exc_inc,
+    CivilDate, DayCountDate, DayCountFraction, DayCounter, Frequency,
+};
+
+/// Actual/Actual (ISDA)
+///
+/// The period is split at calendar year boundaries, and the days falling in
+/// each calendar year are divided by the number of days in that year.
+///
+/// $$
+/// \frac{\text{Days in non-leap years}}{365} + \frac{\text{Days in leap years}}{366}
+/// $$
+///
+/// where the first day of the period is included and the last day is
+/// excluded.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct ActualActualISDA;
+
+impl DayCounter for ActualActualISDA {
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        if start > end {
+            return -self.day_count_fraction(end, start);
+        }
+
+        let y1 = start.year();
+        let y2 = end.year();
+
+        if y1 == y2 {
+            return actual_fraction(start, end, 0, (i64::from(start.days_in_year()), 1));
+        }
+
+        let start_of_next_year = T::from_ymd(y1 + 1, 1, 1);
+        let start_of_end_year = T::from_ymd(y2, 1, 1);
+
+        let first = actual_fraction(
+            start,
+            &start_of_next_year,
+            0,
+            (i64::from(start.days_in_year()), 1),
+        );
+        let last = actual_fraction(
+            &start_of_end_year,
+            end,
+            0,
+            (i64::from(end.days_in_year()), 1),
+        );
+
+        first + DayCountFraction::from_ratio(i64::from(y2 - y1 - 1), 1) + last
+    }
+
+    fn days_in_basis<T: DayCountDate>(&self, start: &T, end: &T) -> Option<f64> {
+        (start.year() == end.year()).then(|| f64::from(start.days_in_year()))
+    }
+}
+
+impl std::fmt::Display for ActualActualISDA {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Actual/Actual (ISDA)")
+    }
+}
+
+/// Actual/Actual (ICMA)
+///
+/// For a period lying within a single coupon (reference) period,
+///
+/// $$
+/// \frac{d_2 - d_1}{F \times (R_2 - R_1)}
+/// $$
+///
+/// where $R_1$ and $R_2$ are the start and end of the reference period and
+/// $F$ is the coupon frequency (number of coupons per year).
+///
+/// Periods that extend before the start or after the end of the reference
+/// period (long front or back stubs) are split into notional coupon periods,
+/// obtained by rolling the reference dates backwards or forwards by whole
+/// coupon periods, and the fractions for each piece are summed. When both
+/// reference dates are month-ends, so are the notional coupon dates.
+///
+/// The [`Default`] reference period is the annual coupon period starting on
+/// 1970-01-01.
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(tag = "convention", rename = "Actual/Actual (ICMA)")
+)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ActualActualICMA {
+    reference_start: CivilDate,
+    reference_end: CivilDate,
+    frequency: Frequency,
+}
+
+impl ActualActualICMA {
+    /// Create a new [`ActualActualICMA`] with a given reference period and coupon frequency.
+    ///
+    /// Returns [`None`] unless the reference period starts before it ends.
+    #[must_use]
+    pub fn new<T: DayCountDate>(
+        reference_start: T,
+        reference_end: T,
+        frequency: Frequency,
+    ) -> Option<Self> {
+        (reference_start < reference_end).then(|| Self {
+            reference_start: reference_start.to_civil(),
+            reference_end: reference_end.to_civil(),
+            frequency,
+        })
+    }
+
+    /// Start of the coupon period the accrual period refers to.
+    #[must_use]
+    pub const fn reference_start(&self) -> CivilDate {
+        self.reference_start
+    }
+
+    /// End of the coupon period the accrual period refers to.
+    #[must_use]
+    pub const fn reference_end(&self) -> CivilDate {
+        self.reference_end
+    }
+
+    /// Coupon frequency of the instrument.
+    #[must_use]
+    pub const fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    /// The `index`-th boundary of the notional coupon periods, where the
+    /// reference period runs from boundary 0 to boundary 1. Boundaries are
+    /// rolled from the reference dates by whole coupon periods, and stay at
+    /// the end of the month when both reference dates are month-ends.
+    fn boundary(&self, index: i32) -> CivilDate {
+        let (anchor, periods) = if index <= 0 {
+            (self.reference_start, index)
+        } else {
+            (self.reference_end, index - 1)
+        };
+        let date = add_months(anchor, periods * self.frequency.months() as i32);
+        if self.reference_start.is_last_day_of_month() && self.reference_end.is_last_day_of_month()
+        {
+            CivilDate::from_ymd(
+                date.year(),
+                date.month(),
+                get_last_day_of_month(date.year(), date.month()) as u32,
+            )
+        } else {
+            date
+        }
+    }
+
+    /// The `index`-th boundary, at the start of the day for date-times.
+    fn boundary_date<T: DayCountDate>(&self, index: i32) -> T {
+        let date = self.boundary(index);
+        T::from_ymd(date.year(), date.month(), date.day())
+    }
+}
+
+impl Default for ActualActualICMA {
+    fn default() -> Self {
+        let reference_start = CivilDate::default();
+        Self {
+            reference_start,
+            reference_end: add_months(reference_start, 12),
+            frequency: Frequency::Annual,
+        }
+    }
+}
+
+impl DayCounter for ActualActualICMA {
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        if start > end {
+            return -self.day_count_fraction(end, start);
+        }
+
+        let mut index = 0;
+        while self.boundary_date::<T>(index) > *start {
+            index -= 1;
+        }
+        while self.boundary_date::<T>(index + 1) <= *start {
+            index += 1;
+        }
+
+        let periods_per_year = i64::from(self.frequency.periods_per_year());
+        let mut fraction = DayCountFraction::from_ratio(0, 1);
+        let mut period_start: T = self.boundary_date(index);
+        while period_start < *end {
+            let period_end: T = self.boundary_date(index + 1);
+            let period_days = actual_days(&period_start, &period_end);
+            let piece_start = if *start > period_start {
+                *start
+            } else {
+                period_start
+            };
+            let piece_end = if *end < period_end { *end } else { period_end };
+            fraction += actual_fraction(
+                &piece_start,
+                &piece_end,
+                0,
+                (periods_per_year * period_days, 1),
+            );
+            period_start = period_end;
+            index += 1;
+        }
+        fraction
+    }
+
+    fn days_in_basis<T: DayCountDate>(&self, start: &T, end: &T) -> Option<f64> {
+        let (start, end) = (start.to_civil(), end.to_civil());
+        let within_reference_period =
+            start.min(end) >= self.reference_start && start.max(end) <= self.reference_end;
+        within_reference_period.then(|| {
+            f64::from(self.frequency.periods_per_year())
+                * self.reference_start.days_until(&self.reference_end)
+        })
+    }
+}
+
+impl std::fmt::Display for ActualActualICMA {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Actual/Actual (ICMA)")
+    }
+}
+
+/// Actual/Actual (AFB)
+///
+/// Whole years are counted backwards from $d_2$ for as long as they remain
+/// on or after $d_1$. If $N$ is the number of whole years and $d_2^*$ is the
+/// date reached after stepping back $N$ years, then
+///
+/// $$
+/// N + \frac{d_2^* - d_1}{\text{Denominator}}
+/// $$
+///
+/// where $\text{Denominator}$ is 366 if February 29th is between
+/// $d_1$ (exclusive) and $d_2^*$ (inclusive), and 365 otherwise.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct ActualActualAFB;
+
+impl ActualActualAFB {
+    /// The number of whole years counted backwards from `end`, and the date
+    /// reached after stepping them back. For date-times, the time of day of
+    /// `end` is kept when stepping back, although the date returned is at the
+    /// start of its day.
+    fn whole_years<T: DayCountDate>(start: &T, end: &T) -> (i32, T) {
+        let time_of_day = Self::start_of_day(end).days_until(end);
+        let mut years = 0;
+        let mut residual_end = *end;
+        loop {
+            let candidate = add_months(end.to_civil(), -12 * (years + 1));
+            let candidate = T::from_ymd(candidate.year(), candidate.month(), candidate.day());
+            if start.days_until(&candidate) + time_of_day < 0.0 {
+                break;
+            }
+            years += 1;
+            residual_end = candidate;
+        }
+        (years, residual_end)
+    }
+
+    fn start_of_day<T: DayCountDate>(date: &T) -> T {
+        T::from_ymd(date.year(), date.month(), date.day())
+    }
+
+    fn denominator<T: DayCountDate>(start: &T, residual_end: &T) -> i64 {
+        if is_feb29_between_exc_inc(*start, *residual_end) {
+            366
+        } else {
+            365
+        }
+    }
+}
+
+impl DayCounter for ActualActualAFB {
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        if start > end {
+            return -self.day_count_fraction(end, start);
+        }
+
+        let (years, residual_end) = Self::whole_years(start, end);
+        let denominator = Self::denominator(start, &residual_end);
+        let fraction = DayCountFraction::from_ratio(i64::from(years), 1)
+            + actual_fraction(start, &residual_end, 0, (denominator, 1));
+        if years == 0 {
+            return fraction;
+        }
+        // The time of day of `end` is carried over to the residual period.
+        fraction + actual_fraction(&Self::start_of_day(end), end, 0, (denominator, 1))
+    }
+
+    fn days_in_basis<T: DayCountDate>(&self, start: &T, end: &T) -> Option<f64> {
+        let (start, end) = if start > end {
+            (end, start)
+        } else {
+            (start, end)
+        };
+        let (years, residual_end) = Self::whole_years(start, end);
+        (years == 0).then(|| Self::denominator(start, &residual_end) as f64)
+    }
+}
+
+impl std::fmt::Display for ActualActualAFB {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Actual/Actual (AFB)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ActualActualAFB, ActualActualICMA, ActualActualISDA, CivilDate, DayCountDate,
+        DayCountFraction, DayCounter, Frequency,
+    };
+
+    fn actual_actual_isda<T: DayCountDate>() {
+        let day1 = T::from_ymd(2003, 11, 1);
+        let day2 = T::from_ymd(2004, 5, 1);
+
+        let yf = ActualActualISDA.day_count_fraction(&day1, &day2);
+        assert!((yf.get_fraction() - (61.0 / 365.0 + 121.0 / 366.0)).abs() < 1e-12);
+
+        let yf = ActualActualISDA.day_count_fraction(&day2, &day1);
+        assert!((yf.get_fraction() + (61.0 / 365.0 + 121.0 / 366.0)).abs() < 1e-12);
+    }
+
+    fn actual_actual_icma<T: DayCountDate>() {
+        // Short first coupon.
+        let dc = ActualActualICMA::new(
+            T::from_ymd(2000, 7, 1),
+            T::from_ymd(2001, 7, 1),
+            Frequency::Annual,
+        )
+        .unwrap();
+        let yf = dc.day_count_fraction(&T::from_ymd(2001, 2, 1), &T::from_ymd(2001, 7, 1));
+        assert!((yf.get_fraction() - 0.410_958_904_110).abs() < 1e-12);
+
+        // Long first coupon.
+        let dc = ActualActualICMA::new(
+            T::from_ymd(2003, 1, 15),
+            T::from_ymd(2003, 7, 15),
+            Frequency::SemiAnnual,
+        )
+        .unwrap();
+        let yf = dc.day_count_fraction(&T::from_ymd(2002, 8, 15), &T::from_ymd(2003, 7, 15));
+        assert!((yf.get_fraction() - 0.915_760_869_565).abs() < 1e-12);
+
+        // Long last coupon.
+        let yf = dc.day_count_fraction(&T::from_ymd(2003, 7, 15), &T::from_ymd(2004, 3, 15));
+        assert!((yf.get_fraction() - (0.5 + 60.0 / 364.0)).abs() < 1e-12);
+
+        // End-of-month coupons, rolled from either side of February 29th.
+        let day1 = T::from_ymd(2023, 8, 31);
+        let day2 = T::from_ymd(2024, 8, 31);
+        let dc = ActualActualICMA::new(
+            T::from_ymd(2023, 8, 31),
+            T::from_ymd(2024, 2, 29),
+            Frequency::SemiAnnual,
+        )
+        .unwrap();
+        assert!(dc.day_count_fraction(&day1, &day2) == DayCountFraction::from_ratio(1, 1));
+        let dc = ActualActualICMA::new(
+            T::from_ymd(2024, 2, 29),
+            T::from_ymd(2024, 8, 31),
+            Frequency::SemiAnnual,
+        )
+        .unwrap();
+        assert!(dc.day_count_fraction(&day1, &day2) == DayCountFraction::from_ratio(1, 1));
+        let yf = dc.day_count_fraction(&T::from_ymd(2024, 8, 31), &T::from_ymd(2024, 12, 31));
+        assert!((yf.get_fraction() - 122.0 / 362.0).abs() < 1e-12);
+    }
+
+    fn actual_actual_afb<T: DayCountDate>() {
+        let yf =
+            ActualActualAFB.day_count_fraction(&T::from_ymd(2003, 11, 1), &T::from_ymd(2004, 5, 1));
+        assert!((yf.get_fraction() - 182.0 / 366.0).abs() < 1e-12);
+
+        let yf =
+            ActualActualAFB.day_count_fraction(&T::from_ymd(2001, 1, 1), &T::from_ymd(2004, 3, 1));
+        assert!((yf.get_fraction() - (3.0 + 59.0 / 365.0)).abs() < 1e-12);
+    }
+
+    fn actual_actual_icma_reference_period<T: DayCountDate>() {
+        let day = T::from_ymd(2024, 1, 1);
+        assert!(ActualActualICMA::new(day, day, Frequency::Annual).is_none());
+        assert!(
+            ActualActualICMA::new(T::from_ymd(2024, 7, 1), day, Frequency::SemiAnnual).is_none()
+        );
+
+        let dc = ActualActualICMA::default();
+        assert!(dc.reference_start() < dc.reference_end());
+        let yf = dc.day_count_fraction(&T::from_ymd(1970, 1, 1), &T::from_ymd(1970, 7, 1));
+        assert_eq!(yf, DayCountFraction::from_ratio(181, 365));
+    }
+
+    #[test]
+    fn actual_actual_icma_many_periods() {
+        let dc = ActualActualICMA::new(
+            CivilDate::from_ymd(2024, 1, 1),
+            CivilDate::from_ymd(2024, 2, 1),
+            Frequency::Monthly,
+        )
+        .unwrap();
+        let yf = dc.day_count_fraction(
+            &CivilDate::from_ymd(2024, 1, 1),
+            &CivilDate::from_ymd(2600, 1, 1),
+        );
+        assert!((yf.get_fraction() - 576.0).abs() < 1e-9);
+    }
+
+    test_date_backends!(
+        actual_actual_isda,
+        actual_actual_icma,
+        actual_actual_icma_reference_period,
+        actual_actual_afb
+    );
+}
@@ -0,0 +1,92 @@
+use crate::{DayCountDate, DayCountFraction, DayCounter, HolidayCalendar, WeekendsOnly};
+
+/// Business/252
+///
+/// $$
+/// \frac{\text{Business days}}{252}
+/// $$
+///
+/// where $\text{Business days}$ is the number of good business days of the
+/// holiday calendar between $d_1$ (inclusive) and $d_2$ (exclusive).
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(tag = "convention", rename = "Business/252")
+)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Business252<C = WeekendsOnly>
+where
+    C: HolidayCalendar,
+{
+    /// The holiday calendar used to determine business days.
+    pub calendar: C,
+}
+
+impl<C> Business252<C>
+where
+    C: HolidayCalendar,
+{
+    /// Create a new [`Business252`] with a given holiday calendar.
+    #[must_use]
+    pub const fn new(calendar: C) -> Self {
+        Self { calendar }
+    }
+}
+
+impl<C> DayCounter for Business252<C>
+where
+    C: HolidayCalendar,
+{
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        DayCountFraction::from_ratio(self.day_count(start, end), 252)
+    }
+
+    fn day_count<T: DayCountDate>(&self, start: &T, end: &T) -> i64 {
+        if start > end {
+            return -self.day_count(end, start);
+        }
+
+        let end = end.to_civil();
+        let mut business_days = 0;
+        let mut date = start.to_civil();
+        while date < end {
+            if self.calendar.is_business_day(&date) {
+                business_days += 1;
+            }
+            date = date.add_days(1);
+        }
+        business_days
+    }
+
+    fn days_in_basis<T: DayCountDate>(&self, _start: &T, _end: &T) -> Option<f64> {
+        Some(252.0)
+    }
+}
+
+impl<C> std::fmt::Display for Business252<C>
+where
+    C: HolidayCalendar,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Business/252")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Business252, CivilDate, DayCountDate, DayCounter, HolidayList, WeekendsOnly};
+
+    fn business_252<T: DayCountDate>() {
+        let day1 = T::from_ymd(2024, 1, 1);
+        let day2 = T::from_ymd(2024, 1, 15);
+
+        let yf = Business252::<WeekendsOnly>::default().day_count_fraction(&day1, &day2);
+        assert!((yf.get_fraction() - 10.0 / 252.0).abs() < 1e-12);
+
+        let calendar = HolidayList::new(vec![CivilDate::from_ymd(2024, 1, 1)]);
+        let yf = Business252::new(calendar).day_count_fraction(&day1, &day2);
+        assert!((yf.get_fraction() - 9.0 / 252.0).abs() < 1e-12);
+    }
+
+    test_date_backends!(business_252);
+}
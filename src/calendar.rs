@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use crate::CivilDate;
+
+/// The [`HolidayCalendar`] trait represents a set of non-business days, used by
+/// business-day based conventions such as [Business/252](crate::Business252).
+///
+/// Saturdays and Sundays are never business days. Only one method is required:
+/// [`is_holiday`](HolidayCalendar::is_holiday).
+pub trait HolidayCalendar: Default + Clone {
+    /// Whether the given date is a holiday.
+    fn is_holiday(&self, date: &CivilDate) -> bool;
+
+    /// Whether the given date is a good business day, i.e. neither a weekend
+    /// nor a holiday.
+    fn is_business_day(&self, date: &CivilDate) -> bool {
+        !date.is_weekend() && !self.is_holiday(date)
+    }
+}
+
+/// Calendar in which every weekday is a business day.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct WeekendsOnly;
+
+impl HolidayCalendar for WeekendsOnly {
+    fn is_holiday(&self, _date: &CivilDate) -> bool {
+        false
+    }
+}
+
+/// Calendar backed by an explicit list of holidays.
+///
+/// The list is shared between clones of the calendar, so calendars loaded at
+/// runtime (e.g. from a file or a trade feed) are cheap to clone.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HolidayList {
+    /// The holidays of the calendar, in any order.
+    pub holidays: Arc<[CivilDate]>,
+}
+
+impl HolidayList {
+    /// Create a new [`HolidayList`] from a list of holidays, e.g. a
+    /// [`Vec`] or a slice of [`CivilDate`]s.
+    #[must_use]
+    pub fn new<H: Into<Arc<[CivilDate]>>>(holidays: H) -> Self {
+        Self {
+            holidays: holidays.into(),
+        }
+    }
+}
+
+impl HolidayCalendar for HolidayList {
+    fn is_holiday(&self, date: &CivilDate) -> bool {
+        self.holidays.contains(date)
+    }
+}
@@ -0,0 +1,215 @@
+use crate::excel::{
+    YearFrac30360US, YearFrac30E360, YearFracActual360, YearFracActual365, YearFracActualActual,
+};
+use crate::{
+    Actual360, Actual360Inc, Actual364, Actual36525, Actual36525Inc, Actual365A, Actual365Canadian,
+    Actual365Fixed, Actual365L, Actual366, Actual366Inc, ActualActualAFB, ActualActualICMA,
+    ActualActualISDA, Business252, DayCountDate, DayCounter, HolidayList, OneOne, Thirty360,
+    Thirty360German, Thirty360ICMA, Thirty360US, Thirty365, ThirtyE360, ThirtyE360ISDA,
+    ThirtyEPlus360ISDA, NL365,
+};
+
+/// Error returned when converting a [`DayCountConvention`] into a concrete
+/// day counter of a different convention.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConventionMismatchError {
+    /// The convention that was held by the [`DayCountConvention`].
+    pub found: DayCountConvention,
+    /// The name of the day counter that was requested.
+    pub expected: &'static str,
+}
+
+impl std::fmt::Display for ConventionMismatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expected day count convention {}, found {}",
+            self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ConventionMismatchError {}
+
+macro_rules! day_count_convention {
+    ($($variant:ident($ty:ty)),* $(,)?) => {
+        /// A day count convention selected at runtime.
+        ///
+        /// Each concrete day counter is its own type, which prevents mixing
+        /// fractions from different conventions, but requires the convention to
+        /// be known at compile time. [`DayCountConvention`] wraps any of them,
+        /// so that the convention can be chosen from trade data. The resulting
+        /// fractions are untyped [`f64`]s.
+        ///
+        /// [Business/252](Business252) is held with a [`HolidayList`] calendar,
+        /// in which an empty list has no holidays besides weekends.
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum DayCountConvention {
+            $(
+                #[doc = concat!("See [`", stringify!($variant), "`](", stringify!($variant), ").")]
+                $variant($ty),
+            )*
+        }
+
+        impl DayCountConvention {
+            /// Given a two dates, calculate the day-count-fraction between the two dates.
+            #[must_use]
+            pub fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> f64 {
+                match self {
+                    $(Self::$variant(dc) => dc.day_count_fraction(start, end).get_fraction(),)*
+                }
+            }
+
+            /// Given two dates, calculate the number of days counted by the
+            /// convention. See [`DayCounter::day_count`].
+            #[must_use]
+            pub fn day_count<T: DayCountDate>(&self, start: &T, end: &T) -> i64 {
+                match self {
+                    $(Self::$variant(dc) => dc.day_count(start, end),)*
+                }
+            }
+
+            /// Given two dates, the number of days in the basis of the
+            /// convention. See [`DayCounter::days_in_basis`].
+            #[must_use]
+            pub fn days_in_basis<T: DayCountDate>(&self, start: &T, end: &T) -> Option<f64> {
+                match self {
+                    $(Self::$variant(dc) => dc.days_in_basis(start, end),)*
+                }
+            }
+
+            /// Given a start date and a target day-count-fraction, the earliest
+            /// date reaching the target. See [`DayCounter::date_from_fraction`].
+            #[must_use]
+            pub fn date_from_fraction<T: DayCountDate>(&self, start: &T, fraction: f64) -> Option<T> {
+                match self {
+                    $(Self::$variant(dc) => dc.date_from_fraction(start, fraction),)*
+                }
+            }
+
+            /// Given two dates, calculate the day-count-fraction as a rounded
+            /// [`Decimal`](rust_decimal::Decimal). See
+            /// [`DayCounter::day_count_fraction_decimal`].
+            #[cfg(feature = "decimal")]
+            #[must_use]
+            pub fn day_count_fraction_decimal<T: DayCountDate>(
+                &self,
+                start: &T,
+                end: &T,
+                decimal_places: u32,
+                strategy: rust_decimal::RoundingStrategy,
+            ) -> Option<rust_decimal::Decimal> {
+                match self {
+                    $(Self::$variant(dc) => {
+                        dc.day_count_fraction_decimal(start, end, decimal_places, strategy)
+                    })*
+                }
+            }
+        }
+
+        impl std::fmt::Display for DayCountConvention {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(Self::$variant(dc) => dc.fmt(f),)*
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for DayCountConvention {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                match self {
+                    $(Self::$variant(dc) => dc.serialize(serializer),)*
+                }
+            }
+        }
+
+        $(
+            impl From<$ty> for DayCountConvention {
+                fn from(dc: $ty) -> Self {
+                    Self::$variant(dc)
+                }
+            }
+
+            impl TryFrom<DayCountConvention> for $ty {
+                type Error = ConventionMismatchError;
+
+                fn try_from(convention: DayCountConvention) -> Result<Self, Self::Error> {
+                    match convention {
+                        DayCountConvention::$variant(dc) => Ok(dc),
+                        found => Err(ConventionMismatchError {
+                            found,
+                            expected: stringify!($variant),
+                        }),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+day_count_convention!(
+    Actual360(Actual360),
+    Actual360Inc(Actual360Inc),
+    Actual364(Actual364),
+    Actual365A(Actual365A),
+    Actual365Canadian(Actual365Canadian),
+    Actual365Fixed(Actual365Fixed),
+    Actual365L(Actual365L),
+    Actual366(Actual366),
+    Actual366Inc(Actual366Inc),
+    Actual36525(Actual36525),
+    Actual36525Inc(Actual36525Inc),
+    ActualActualAFB(ActualActualAFB),
+    ActualActualICMA(ActualActualICMA),
+    ActualActualISDA(ActualActualISDA),
+    Business252(Business252<HolidayList>),
+    NL365(NL365),
+    OneOne(OneOne),
+    Thirty360(Thirty360),
+    Thirty360German(Thirty360German),
+    Thirty360ICMA(Thirty360ICMA),
+    Thirty360US(Thirty360US),
+    Thirty365(Thirty365),
+    ThirtyE360(ThirtyE360),
+    ThirtyE360ISDA(ThirtyE360ISDA),
+    ThirtyEPlus360ISDA(ThirtyEPlus360ISDA),
+    YearFrac30360US(YearFrac30360US),
+    YearFracActualActual(YearFracActualActual),
+    YearFracActual360(YearFracActual360),
+    YearFracActual365(YearFracActual365),
+    YearFrac30E360(YearFrac30E360),
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::excel::YearFrac30360US;
+    use crate::{Actual360, DayCountConvention, DayCountDate, DayCounter, Thirty360};
+
+    fn runtime_convention<T: DayCountDate>() {
+        let day1 = T::from_ymd(2024, 1, 1);
+        let day2 = T::from_ymd(2024, 7, 1);
+
+        let convention = DayCountConvention::from(Actual360);
+        assert_eq!(
+            convention.day_count_fraction(&day1, &day2),
+            Actual360.day_count_fraction(&day1, &day2).get_fraction()
+        );
+        assert_eq!(convention.to_string(), "Actual/360");
+
+        assert_eq!(Actual360::try_from(convention.clone()), Ok(Actual360));
+        assert!(Thirty360::try_from(convention).is_err());
+
+        let convention = DayCountConvention::from(YearFrac30360US);
+        assert_eq!(
+            convention.day_count_fraction(&day1, &day2),
+            YearFrac30360US
+                .day_count_fraction(&day1, &day2)
+                .get_fraction()
+        );
+        assert_eq!(convention.to_string(), "YEARFRAC (basis 0)");
+        assert_eq!(YearFrac30360US::try_from(convention), Ok(YearFrac30360US));
+    }
+
+    test_date_backends!(runtime_convention);
+}
@@ -0,0 +1,425 @@
+use crate::{get_days_in_year, get_last_day_of_month, is_leap_year};
+
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, NaiveDate};
+#[cfg(feature = "hifitime")]
+use hifitime::Epoch;
+#[cfg(feature = "jiff")]
+use jiff::{civil, Zoned};
+#[cfg(feature = "time")]
+use time::{Date, Month};
+
+/// The [`DayCountDate`] trait represents the date types accepted by the day
+/// counters.
+///
+/// It is implemented for [`CivilDate`], and for `chrono::NaiveDate`,
+/// `hifitime::Epoch`, `time::Date` and `jiff::civil::Date` when the
+/// `chrono`, `hifitime`, `time` and `jiff` features are enabled.
+/// Enabling several backends at once is supported: every day counter accepts
+/// all of them.
+///
+/// Other date types (e.g. integer serial dates) can be used by implementing
+/// this trait. Only [`year`](DayCountDate::year),
+/// [`month`](DayCountDate::month), [`day`](DayCountDate::day) and
+/// [`from_ymd`](DayCountDate::from_ymd) are required; the ordering of the type
+/// must agree with the calendar order of the dates.
+pub trait DayCountDate: Copy + PartialEq + PartialOrd {
+    /// The year of the date.
+    fn year(&self) -> i32;
+
+    /// The month of the date, from 1 to 12.
+    fn month(&self) -> u32;
+
+    /// The day of the month of the date, from 1 to 31.
+    fn day(&self) -> u32;
+
+    /// Create a date from a year, month and day. The day counters only call
+    /// this with valid dates, so implementations may panic otherwise.
+    fn from_ymd(year: i32, month: u32, day: u32) -> Self;
+
+    /// The number of days from `self` to `end`. This is negative if `end` is
+    /// before `self`.
+    ///
+    /// The default implementation counts the calendar days between the two
+    /// dates.
+    fn days_until(&self, end: &Self) -> f64 {
+        self.to_civil().days_until(&end.to_civil())
+    }
+
+    /// Whether the year of the date is a leap year.
+    fn is_leap_year(&self) -> bool {
+        is_leap_year(self.year())
+    }
+
+    /// The number of days in the year of the date, i.e. 365 or 366.
+    fn days_in_year(&self) -> u32 {
+        get_days_in_year(self.year()).unsigned_abs()
+    }
+
+    /// The number of days in the month of the date.
+    fn days_in_month(&self) -> u32 {
+        get_last_day_of_month(self.year(), self.month()).unsigned_abs()
+    }
+
+    /// Whether the date is the last day of its month.
+    fn is_last_day_of_month(&self) -> bool {
+        self.day() == self.days_in_month()
+    }
+
+    /// The calendar date of `self`.
+    fn to_civil(&self) -> CivilDate {
+        CivilDate {
+            year: self.year(),
+            month: self.month(),
+            day: self.day(),
+        }
+    }
+}
+
+/// A calendar date in the proleptic Gregorian calendar.
+///
+/// This is the date type used for the dates stored by the day counters (e.g.
+/// the termination date of [`ThirtyE360ISDA`](crate::ThirtyE360ISDA)), so that
+/// they do not depend on the enabled date backends. It can also be used as a
+/// date backend by itself.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CivilDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl CivilDate {
+    /// Create a new [`CivilDate`], or `None` if the date is invalid.
+    #[must_use]
+    pub const fn from_ymd_opt(year: i32, month: u32, day: u32) -> Option<Self> {
+        if month < 1 || month > 12 || day < 1 || day as i32 > get_last_day_of_month(year, month) {
+            None
+        } else {
+            Some(Self { year, month, day })
+        }
+    }
+
+    /// Create a new [`CivilDate`] from any supported date type.
+    #[must_use]
+    pub fn from_date<T: DayCountDate>(date: &T) -> Self {
+        date.to_civil()
+    }
+
+    /// The number of days since 1970-01-01.
+    #[must_use]
+    pub const fn num_days_from_unix_epoch(&self) -> i64 {
+        let year = (if self.month <= 2 {
+            self.year - 1
+        } else {
+            self.year
+        }) as i64;
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = self.month as i64;
+        let day_of_year =
+            (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Create a [`CivilDate`] from the number of days since 1970-01-01.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub const fn from_num_days_from_unix_epoch(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+        let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+        let year = (year_of_era + era * 400) as i32 + if month <= 2 { 1 } else { 0 };
+        Self { year, month, day }
+    }
+
+    /// The date `days` days after `self` (or before, if negative).
+    #[must_use]
+    pub const fn add_days(&self, days: i64) -> Self {
+        Self::from_num_days_from_unix_epoch(self.num_days_from_unix_epoch() + days)
+    }
+
+    /// Whether the date is a Saturday or a Sunday.
+    #[must_use]
+    pub const fn is_weekend(&self) -> bool {
+        // 1970-01-01 was a Thursday.
+        matches!(self.num_days_from_unix_epoch().rem_euclid(7), 2 | 3)
+    }
+}
+
+impl Default for CivilDate {
+    fn default() -> Self {
+        Self {
+            year: 1970,
+            month: 1,
+            day: 1,
+        }
+    }
+}
+
+impl std::fmt::Display for CivilDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl DayCountDate for CivilDate {
+    fn year(&self) -> i32 {
+        self.year
+    }
+    fn month(&self) -> u32 {
+        self.month
+    }
+    fn day(&self) -> u32 {
+        self.day
+    }
+    fn from_ymd(year: i32, month: u32, day: u32) -> Self {
+        Self { year, month, day }
+    }
+    fn days_until(&self, end: &Self) -> f64 {
+        (end.num_days_from_unix_epoch() - self.num_days_from_unix_epoch()) as f64
+    }
+    fn to_civil(&self) -> CivilDate {
+        *self
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl DayCountDate for NaiveDate {
+    fn year(&self) -> i32 {
+        Datelike::year(self)
+    }
+    fn month(&self) -> u32 {
+        Datelike::month(self)
+    }
+    fn day(&self) -> u32 {
+        Datelike::day(self)
+    }
+    fn from_ymd(year: i32, month: u32, day: u32) -> Self {
+        Self::from_ymd_opt(year, month, day).unwrap()
+    }
+    fn days_until(&self, end: &Self) -> f64 {
+        (*end - *self).num_days() as f64
+    }
+}
+
+/// Epochs are truncated to their UTC date. See
+/// [`ActualDayCounter`](crate::ActualDayCounter) to take the time of day into
+/// account.
+#[cfg(feature = "hifitime")]
+impl DayCountDate for Epoch {
+    fn year(&self) -> i32 {
+        self.to_gregorian_utc().0
+    }
+    fn month(&self) -> u32 {
+        u32::from(self.to_gregorian_utc().1)
+    }
+    fn day(&self) -> u32 {
+        u32::from(self.to_gregorian_utc().2)
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_ymd(year: i32, month: u32, day: u32) -> Self {
+        Self::from_gregorian_utc_at_midnight(year, month as u8, day as u8)
+    }
+    fn to_civil(&self) -> CivilDate {
+        let (year, month, day, _, _, _, _) = self.to_gregorian_utc();
+        CivilDate {
+            year,
+            month: u32::from(month),
+            day: u32::from(day),
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl DayCountDate for Date {
+    fn year(&self) -> i32 {
+        Self::year(*self)
+    }
+    fn month(&self) -> u32 {
+        u32::from(u8::from(Self::month(*self)))
+    }
+    fn day(&self) -> u32 {
+        u32::from(Self::day(*self))
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_ymd(year: i32, month: u32, day: u32) -> Self {
+        let month = Month::try_from(month as u8).unwrap();
+        Self::from_calendar_date(year, month, day as u8).unwrap()
+    }
+    fn days_until(&self, end: &Self) -> f64 {
+        (*end - *self).whole_days() as f64
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl DayCountDate for civil::Date {
+    fn year(&self) -> i32 {
+        i32::from(Self::year(*self))
+    }
+    #[allow(clippy::cast_sign_loss)]
+    fn month(&self) -> u32 {
+        Self::month(*self) as u32
+    }
+    #[allow(clippy::cast_sign_loss)]
+    fn day(&self) -> u32 {
+        Self::day(*self) as u32
+    }
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    fn from_ymd(year: i32, month: u32, day: u32) -> Self {
+        civil::date(year as i16, month as i8, day as i8)
+    }
+    fn days_until(&self, end: &Self) -> f64 {
+        f64::from(end.since(*self).unwrap().get_days())
+    }
+}
+
+/// Date-times are truncated to their date. See
+/// [`ActualDayCounter`](crate::ActualDayCounter) to take the time of day into
+/// account.
+#[cfg(feature = "jiff")]
+impl DayCountDate for civil::DateTime {
+    fn year(&self) -> i32 {
+        DayCountDate::year(&self.date())
+    }
+    fn month(&self) -> u32 {
+        DayCountDate::month(&self.date())
+    }
+    fn day(&self) -> u32 {
+        DayCountDate::day(&self.date())
+    }
+    fn from_ymd(year: i32, month: u32, day: u32) -> Self {
+        civil::Date::from_ymd(year, month, day).into()
+    }
+    fn days_until(&self, end: &Self) -> f64 {
+        self.date().days_until(&end.date())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<NaiveDate> for CivilDate {
+    fn from(date: NaiveDate) -> Self {
+        date.to_civil()
+    }
+}
+
+#[cfg(feature = "hifitime")]
+impl From<Epoch> for CivilDate {
+    fn from(date: Epoch) -> Self {
+        date.to_civil()
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl From<civil::Date> for CivilDate {
+    fn from(date: civil::Date) -> Self {
+        date.to_civil()
+    }
+}
+
+/// Zoned date-times are truncated to their date in their own time zone.
+#[cfg(feature = "jiff")]
+impl From<&Zoned> for CivilDate {
+    fn from(date: &Zoned) -> Self {
+        date.date().to_civil()
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<Date> for CivilDate {
+    fn from(date: Date) -> Self {
+        date.to_civil()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Actual360, Actual365Fixed, ActualActualISDA, CivilDate, DayCountDate, DayCounter,
+        Thirty360US,
+    };
+
+    /// A date stored as the number of days since 1970-01-01.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    struct Serial(i32);
+
+    impl DayCountDate for Serial {
+        fn year(&self) -> i32 {
+            CivilDate::from_num_days_from_unix_epoch(i64::from(self.0)).year()
+        }
+        fn month(&self) -> u32 {
+            CivilDate::from_num_days_from_unix_epoch(i64::from(self.0)).month()
+        }
+        fn day(&self) -> u32 {
+            CivilDate::from_num_days_from_unix_epoch(i64::from(self.0)).day()
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        fn from_ymd(year: i32, month: u32, day: u32) -> Self {
+            let date = CivilDate::from_ymd_opt(year, month, day).unwrap();
+            Self(date.num_days_from_unix_epoch() as i32)
+        }
+        fn days_until(&self, end: &Self) -> f64 {
+            f64::from(end.0 - self.0)
+        }
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn jiff_date_times() {
+        use jiff::{civil, tz::TimeZone};
+
+        let zoned = civil::date(2024, 2, 29)
+            .at(23, 30, 0, 0)
+            .to_zoned(TimeZone::fixed(jiff::tz::offset(-5)))
+            .unwrap();
+        assert_eq!(
+            CivilDate::from(&zoned),
+            CivilDate::from_ymd_opt(2024, 2, 29).unwrap()
+        );
+        assert!(zoned.date().is_last_day_of_month() && zoned.date().is_leap_year());
+
+        let day1 = civil::date(2024, 1, 1).at(18, 0, 0, 0);
+        let day2 = civil::date(2024, 7, 1).at(6, 0, 0, 0);
+        assert!(
+            Actual360.day_count_fraction(&day1, &day2)
+                == Actual360.day_count_fraction(&day1.date(), &day2.date())
+        );
+    }
+
+    #[test]
+    fn custom_date_type() {
+        let day1 = Serial::from_ymd(2023, 11, 30);
+        let day2 = Serial::from_ymd(2024, 2, 29);
+        assert_eq!(
+            day2.to_civil(),
+            CivilDate::from_ymd_opt(2024, 2, 29).unwrap()
+        );
+        assert!(day2.is_leap_year() && day2.is_last_day_of_month());
+        assert_eq!(day1.days_in_year(), 365);
+
+        let (civil1, civil2) = (day1.to_civil(), day2.to_civil());
+        assert!(
+            Actual360.day_count_fraction(&day1, &day2)
+                == Actual360.day_count_fraction(&civil1, &civil2)
+        );
+        assert!(
+            Actual365Fixed.day_count_fraction(&day1, &day2)
+                == Actual365Fixed.day_count_fraction(&civil1, &civil2)
+        );
+        assert!(
+            ActualActualISDA.day_count_fraction(&day1, &day2)
+                == ActualActualISDA.day_count_fraction(&civil1, &civil2)
+        );
+        assert!(
+            Thirty360US::default().day_count_fraction(&day1, &day2)
+                == Thirty360US::default().day_count_fraction(&civil1, &civil2)
+        );
+    }
+}
@@ -0,0 +1,253 @@
+//! Day counters reproducing Microsoft Excel's `YEARFRAC` function.
+//!
+//! Excel's implementation deviates from the textbook conventions in a few
+//! places (e.g. the February handling of basis 0 and the averaging of year
+//! lengths in basis 1), so these are provided separately from the standard
+//! conventions. As in Excel, the order of the two dates does not matter: the
+//! fraction is always computed from the earlier date to the later one.
+
+use crate::{CivilDate, DayCountDate, DayCountFraction, DayCounter};
+
+fn to_ordered<T: DayCountDate>(start: &T, end: &T) -> (CivilDate, CivilDate) {
+    let start = start.to_civil();
+    let end = end.to_civil();
+    (start.min(end), start.max(end))
+}
+
+fn days_between(start: CivilDate, end: CivilDate) -> i64 {
+    end.num_days_from_unix_epoch() - start.num_days_from_unix_epoch()
+}
+
+fn basis_0(start: CivilDate, end: CivilDate) -> (i64, (i64, i64)) {
+    let mut d1 = start.day() as i32;
+    let mut d2 = end.day() as i32;
+
+    if d1 == 31 && d2 == 31 {
+        d1 = 30;
+        d2 = 30;
+    } else if d1 == 31 {
+        d1 = 30;
+    } else if d1 == 30 && d2 == 31 {
+        d2 = 30;
+    } else if start.month() == 2
+        && end.month() == 2
+        && start.is_last_day_of_month()
+        && end.is_last_day_of_month()
+    {
+        d1 = 30;
+        d2 = 30;
+    } else if start.month() == 2 && start.is_last_day_of_month() {
+        d1 = 30;
+    }
+
+    let numerator = 360 * (end.year() - start.year())
+        + 30 * (end.month() as i32 - start.month() as i32)
+        + (d2 - d1);
+    (i64::from(numerator), (360, 1))
+}
+
+fn basis_1(start: CivilDate, end: CivilDate) -> (i64, (i64, i64)) {
+    let days = days_between(start, end);
+
+    let appears_within_a_year = start.year() == end.year()
+        || (start.year() + 1 == end.year()
+            && (start.month() > end.month()
+                || (start.month() == end.month() && start.day() >= end.day())));
+
+    if appears_within_a_year {
+        let march_1_start = CivilDate::from_ymd(start.year(), 3, 1);
+        let march_1_end = CivilDate::from_ymd(end.year(), 3, 1);
+        let includes_feb29 =
+            (start.is_leap_year() && start < march_1_start && end >= march_1_start)
+                || (end.is_leap_year() && end >= march_1_end && start < march_1_end);
+
+        let year_length = if (start.year() == end.year() && start.is_leap_year())
+            || includes_feb29
+            || (end.month() == 2 && end.day() == 29)
+        {
+            366
+        } else {
+            365
+        };
+        (days, (year_length, 1))
+    } else {
+        let years = i64::from(end.year() - start.year() + 1);
+        let days_in_years = days_between(
+            CivilDate::from_ymd(start.year(), 1, 1),
+            CivilDate::from_ymd(end.year() + 1, 1, 1),
+        );
+        (days, (days_in_years, years))
+    }
+}
+
+fn basis_4(start: CivilDate, end: CivilDate) -> (i64, (i64, i64)) {
+    let d1 = start.day().min(30) as i32;
+    let d2 = end.day().min(30) as i32;
+
+    let numerator = 360 * (end.year() - start.year())
+        + 30 * (end.month() as i32 - start.month() as i32)
+        + (d2 - d1);
+    (i64::from(numerator), (360, 1))
+}
+
+macro_rules! year_frac {
+    ($(#[$meta:meta])* $name:ident, $basis:literal, $days_and_basis:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+        pub struct $name;
+
+        impl $name {
+            /// The day count, and the basis as a number of days over a number
+            /// of years.
+            fn days_and_basis<T: DayCountDate>(&self, start: &T, end: &T) -> (i64, (i64, i64)) {
+                let (start, end) = to_ordered(start, end);
+                $days_and_basis(start, end)
+            }
+        }
+
+        impl DayCounter for $name {
+            fn day_count_fraction<T: DayCountDate>(
+                &self,
+                start: &T,
+                end: &T,
+            ) -> DayCountFraction<Self> {
+                // The value follows Excel's formula, dividing by the average
+                // year length, so that results match Excel bit for bit.
+                let (days, (basis, years)) = self.days_and_basis(start, end);
+                let fraction = days as f64 / (basis as f64 / years as f64);
+                DayCountFraction::with_value(fraction, days * years, basis)
+            }
+
+            fn day_count<T: DayCountDate>(&self, start: &T, end: &T) -> i64 {
+                self.days_and_basis(start, end).0
+            }
+
+            fn days_in_basis<T: DayCountDate>(&self, start: &T, end: &T) -> Option<f64> {
+                let (_, (basis, years)) = self.days_and_basis(start, end);
+                Some(basis as f64 / years as f64)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, concat!("YEARFRAC (basis ", $basis, ")"))
+            }
+        }
+    };
+}
+
+year_frac!(
+    /// `YEARFRAC` basis 0: US (NASD) 30/360.
+    ///
+    /// Follows the 30/360 rules for the 31st of the month, and additionally
+    /// treats the last day of February of the start date as the 30th (and
+    /// the end date too, if both are the last day of February).
+    YearFrac30360US,
+    0,
+    basis_0
+);
+
+year_frac!(
+    /// `YEARFRAC` basis 1: Actual/Actual.
+    ///
+    /// If the dates are at most a year apart, the actual number of days is
+    /// divided by 366 if the period includes February 29th, and 365
+    /// otherwise. Otherwise, it is divided by the average length of the
+    /// calendar years spanned by the period.
+    YearFracActualActual,
+    1,
+    basis_1
+);
+
+year_frac!(
+    /// `YEARFRAC` basis 2: Actual/360.
+    YearFracActual360,
+    2,
+    |start, end| (days_between(start, end), (360, 1))
+);
+
+year_frac!(
+    /// `YEARFRAC` basis 3: Actual/365.
+    YearFracActual365,
+    3,
+    |start, end| (days_between(start, end), (365, 1))
+);
+
+year_frac!(
+    /// `YEARFRAC` basis 4: European 30/360.
+    YearFrac30E360,
+    4,
+    basis_4
+);
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        YearFrac30360US, YearFrac30E360, YearFracActual360, YearFracActual365, YearFracActualActual,
+    };
+    use crate::{DayCountDate, DayCounter};
+
+    // Results are compared exactly, as they must match Excel bit for bit.
+    fn year_frac<T: DayCountDate>() {
+        let day1 = T::from_ymd(2012, 1, 1);
+        let day2 = T::from_ymd(2012, 7, 30);
+
+        assert_eq!(
+            YearFrac30360US
+                .day_count_fraction(&day1, &day2)
+                .get_fraction(),
+            209.0 / 360.0
+        );
+        assert_eq!(
+            YearFracActualActual
+                .day_count_fraction(&day1, &day2)
+                .get_fraction(),
+            211.0 / 366.0
+        );
+        assert_eq!(
+            YearFracActual360
+                .day_count_fraction(&day1, &day2)
+                .get_fraction(),
+            211.0 / 360.0
+        );
+        assert_eq!(
+            YearFracActual365
+                .day_count_fraction(&day1, &day2)
+                .get_fraction(),
+            211.0 / 365.0
+        );
+        assert_eq!(
+            YearFrac30E360
+                .day_count_fraction(&day1, &day2)
+                .get_fraction(),
+            209.0 / 360.0
+        );
+
+        // Reversed dates give the same result.
+        assert_eq!(
+            YearFrac30360US
+                .day_count_fraction(&day2, &day1)
+                .get_fraction(),
+            209.0 / 360.0
+        );
+
+        // Both dates at the end of February.
+        let day1 = T::from_ymd(2011, 2, 28);
+        let day2 = T::from_ymd(2012, 2, 29);
+        assert_eq!(
+            YearFrac30360US
+                .day_count_fraction(&day1, &day2)
+                .get_fraction(),
+            1.0
+        );
+
+        // Averaged year length over several years.
+        let day1 = T::from_ymd(2010, 1, 1);
+        let day2 = T::from_ymd(2012, 7, 1);
+        let yf = YearFracActualActual.day_count_fraction(&day1, &day2);
+        assert_eq!(yf.get_fraction(), 2.496_350_364_963_504);
+        assert_eq!((yf.numerator(), yf.denominator()), (Some(342), Some(137)));
+    }
+
+    test_date_backends!(year_frac);
+}
@@ -0,0 +1,133 @@
+//! Mapping between day count conventions and the codes of the FpML
+//! [`dayCountFractionScheme`](https://www.fpml.org/coding-scheme/day-count-fraction).
+//!
+//! Not every convention in this crate has an FpML code, and not every FpML
+//! code is implemented by this crate.
+
+use crate::{
+    Actual360, Actual364, Actual365Fixed, ActualActualAFB, ActualActualISDA, DayCountConvention,
+    OneOne, ParseConventionError, Thirty360, ThirtyE360,
+};
+
+/// The FpML `dayCountFractionScheme` code of a convention, if it has one.
+///
+/// Conventions carrying additional parameters (e.g. the termination date of
+/// [`ThirtyE360ISDA`](crate::ThirtyE360ISDA)) map to the same code regardless
+/// of those parameters. Conventions computing the same fraction as one with a
+/// code (e.g. [`Thirty360ICMA`](crate::Thirty360ICMA) and 30E/360) share its
+/// code.
+#[must_use]
+pub const fn to_fpml(convention: &DayCountConvention) -> Option<&'static str> {
+    match convention {
+        DayCountConvention::OneOne(_) => Some("1/1"),
+        DayCountConvention::Thirty360(_) => Some("30/360"),
+        DayCountConvention::ThirtyE360(_) | DayCountConvention::Thirty360ICMA(_) => Some("30E/360"),
+        DayCountConvention::ThirtyE360ISDA(_) | DayCountConvention::Thirty360German(_) => {
+            Some("30E/360.ISDA")
+        }
+        DayCountConvention::Actual360(_) => Some("ACT/360"),
+        DayCountConvention::Actual364(_) => Some("ACT/364"),
+        DayCountConvention::Actual365Fixed(_) => Some("ACT/365.FIXED"),
+        DayCountConvention::Actual365L(_) => Some("ACT/365L"),
+        DayCountConvention::ActualActualAFB(_) => Some("ACT/ACT.AFB"),
+        DayCountConvention::ActualActualICMA(_) => Some("ACT/ACT.ICMA"),
+        DayCountConvention::ActualActualISDA(_) => Some("ACT/ACT.ISDA"),
+        DayCountConvention::Business252(_) => Some("BUS/252"),
+        _ => None,
+    }
+}
+
+/// The convention identified by an FpML `dayCountFractionScheme` code.
+///
+/// # Errors
+///
+/// Returns [`ParseConventionError::Unknown`] if the code is not part of the
+/// scheme, or refers to a convention this crate does not implement, and
+/// [`ParseConventionError::RequiresParameters`] if the convention has
+/// parameters (e.g. the termination date of 30E/360 (ISDA)), which should be
+/// taken from the rest of the trade to build the convention.
+pub fn from_fpml(code: &str) -> Result<DayCountConvention, ParseConventionError> {
+    let requires_parameters = |convention| {
+        Err(ParseConventionError::RequiresParameters {
+            input: code.to_string(),
+            convention,
+        })
+    };
+    match code {
+        "1/1" => Ok(OneOne.into()),
+        "30/360" => Ok(Thirty360.into()),
+        "30E/360" => Ok(ThirtyE360.into()),
+        "30E/360.ISDA" => requires_parameters("30E/360 (ISDA)"),
+        "ACT/360" => Ok(Actual360.into()),
+        "ACT/364" => Ok(Actual364.into()),
+        "ACT/365.FIXED" => Ok(Actual365Fixed.into()),
+        "ACT/365L" => requires_parameters("Actual/365L"),
+        "ACT/ACT.AFB" => Ok(ActualActualAFB.into()),
+        "ACT/ACT.ICMA" | "ACT/ACT.ISMA" => requires_parameters("Actual/Actual (ICMA)"),
+        "ACT/ACT.ISDA" => Ok(ActualActualISDA.into()),
+        "BUS/252" => requires_parameters("Business/252"),
+        _ => Err(ParseConventionError::Unknown(code.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_fpml, to_fpml};
+    use crate::{
+        Actual365L, ActualActualICMA, Business252, DayCountConvention, HolidayList,
+        ParseConventionError, Thirty360German, Thirty360ICMA, Thirty360US, ThirtyE360ISDA,
+    };
+
+    #[test]
+    fn round_trip() {
+        for code in [
+            "1/1",
+            "30/360",
+            "30E/360",
+            "ACT/360",
+            "ACT/364",
+            "ACT/365.FIXED",
+            "ACT/ACT.AFB",
+            "ACT/ACT.ISDA",
+        ] {
+            assert_eq!(to_fpml(&from_fpml(code).unwrap()), Some(code));
+        }
+
+        assert_eq!(
+            to_fpml(&DayCountConvention::from(Thirty360US::default())),
+            None
+        );
+        assert_eq!(
+            from_fpml("RBA"),
+            Err(ParseConventionError::Unknown("RBA".to_string()))
+        );
+    }
+
+    #[test]
+    fn parameterized_codes() {
+        for (code, convention) in [
+            (
+                "30E/360.ISDA",
+                DayCountConvention::from(ThirtyE360ISDA::default()),
+            ),
+            ("ACT/365L", Actual365L::default().into()),
+            ("ACT/ACT.ICMA", ActualActualICMA::default().into()),
+            ("BUS/252", Business252::new(HolidayList::default()).into()),
+        ] {
+            assert!(matches!(
+                from_fpml(code),
+                Err(ParseConventionError::RequiresParameters { .. })
+            ));
+            assert_eq!(to_fpml(&convention), Some(code));
+        }
+
+        assert_eq!(
+            to_fpml(&DayCountConvention::from(Thirty360ICMA)),
+            Some("30E/360")
+        );
+        assert_eq!(
+            to_fpml(&DayCountConvention::from(Thirty360German::default())),
+            Some("30E/360.ISDA")
+        );
+    }
+}
@@ -0,0 +1,49 @@
+/// Coupon frequency of an instrument, used by the conventions that depend on
+/// the length of the coupon period (e.g. [Actual/Actual (ICMA)](crate::ActualActualICMA)).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum Frequency {
+    /// One coupon per year.
+    #[default]
+    Annual,
+    /// Two coupons per year.
+    SemiAnnual,
+    /// Four coupons per year.
+    Quarterly,
+    /// Six coupons per year.
+    Bimonthly,
+    /// Twelve coupons per year.
+    Monthly,
+}
+
+impl Frequency {
+    /// Number of coupon periods in a year.
+    #[must_use]
+    pub const fn periods_per_year(&self) -> u32 {
+        match self {
+            Self::Annual => 1,
+            Self::SemiAnnual => 2,
+            Self::Quarterly => 4,
+            Self::Bimonthly => 6,
+            Self::Monthly => 12,
+        }
+    }
+
+    /// Number of months in a coupon period.
+    #[must_use]
+    pub const fn months(&self) -> u32 {
+        12 / self.periods_per_year()
+    }
+}
+
+impl std::fmt::Display for Frequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Annual => write!(f, "Annual"),
+            Self::SemiAnnual => write!(f, "Semi-Annual"),
+            Self::Quarterly => write!(f, "Quarterly"),
+            Self::Bimonthly => write!(f, "Bimonthly"),
+            Self::Monthly => write!(f, "Monthly"),
+        }
+    }
+}
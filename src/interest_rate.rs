@@ -0,0 +1,313 @@
+use crate::{DayCountDate, DayCounter, Frequency};
+
+/// How interest accrues over a period of $t$ years at a rate $r$.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum Compounding {
+    /// Simple interest, with compound factor
+    /// $$1 + rt$$
+    #[default]
+    Simple,
+    /// Interest compounded $f$ times per year, with compound factor
+    /// $$\left(1 + \frac{r}{f}\right)^{ft}$$
+    Compounded(Frequency),
+    /// Continuously compounded interest, with compound factor
+    /// $$e^{rt}$$
+    Continuous,
+}
+
+impl std::fmt::Display for Compounding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Simple => write!(f, "Simple"),
+            Self::Compounded(frequency) => write!(f, "Compounded ({frequency})"),
+            Self::Continuous => write!(f, "Continuous"),
+        }
+    }
+}
+
+/// An interest rate, together with the day count convention measuring the
+/// periods over which it accrues and its [`Compounding`].
+///
+/// The rate is a decimal (e.g. `0.05` for 5%).
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct InterestRate<D>
+where
+    D: DayCounter,
+{
+    rate: f64,
+    day_counter: D,
+    compounding: Compounding,
+}
+
+impl<D> InterestRate<D>
+where
+    D: DayCounter,
+{
+    /// Create a new interest rate.
+    #[must_use]
+    pub const fn new(rate: f64, day_counter: D, compounding: Compounding) -> Self {
+        Self {
+            rate,
+            day_counter,
+            compounding,
+        }
+    }
+
+    /// The rate, as a decimal.
+    #[must_use]
+    pub const fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// The day count convention of the rate.
+    #[must_use]
+    pub const fn day_counter(&self) -> &D {
+        &self.day_counter
+    }
+
+    /// The compounding of the rate.
+    #[must_use]
+    pub const fn compounding(&self) -> Compounding {
+        self.compounding
+    }
+
+    /// The growth of one unit invested at this rate between the two dates,
+    /// with the period measured by the rate's day count convention.
+    #[must_use]
+    pub fn compound_factor<T: DayCountDate>(&self, start: &T, end: &T) -> f64 {
+        let time = self
+            .day_counter
+            .day_count_fraction(start, end)
+            .get_fraction();
+        match self.compounding {
+            Compounding::Simple => self.rate.mul_add(time, 1.0),
+            Compounding::Compounded(frequency) => {
+                let periods = f64::from(frequency.periods_per_year());
+                (1.0 + self.rate / periods).powf(periods * time)
+            }
+            Compounding::Continuous => (self.rate * time).exp(),
+        }
+    }
+
+    /// The present value at the start date of one unit paid at the end date,
+    /// i.e. the inverse of the [`compound_factor`](Self::compound_factor).
+    #[must_use]
+    pub fn discount_factor<T: DayCountDate>(&self, start: &T, end: &T) -> f64 {
+        1.0 / self.compound_factor(start, end)
+    }
+
+    /// The rate with the given convention and compounding whose compound
+    /// factor between the two dates is `compound_factor`.
+    ///
+    /// Returns [`None`] if the period has no length under the convention, or
+    /// if the compound factor is not positive.
+    #[must_use]
+    pub fn implied_rate<T: DayCountDate>(
+        compound_factor: f64,
+        day_counter: D,
+        compounding: Compounding,
+        start: &T,
+        end: &T,
+    ) -> Option<Self> {
+        let time = day_counter.day_count_fraction(start, end).get_fraction();
+        if time == 0.0 || compound_factor <= 0.0 {
+            return None;
+        }
+        let rate = match compounding {
+            Compounding::Simple => (compound_factor - 1.0) / time,
+            Compounding::Compounded(frequency) => {
+                let periods = f64::from(frequency.periods_per_year());
+                (compound_factor.powf(1.0 / (periods * time)) - 1.0) * periods
+            }
+            Compounding::Continuous => compound_factor.ln() / time,
+        };
+        Some(Self::new(rate, day_counter, compounding))
+    }
+
+    /// The rate with the given convention and compounding which has the same
+    /// compound factor as this rate between the two dates.
+    ///
+    /// Returns [`None`] under the same conditions as
+    /// [`implied_rate`](Self::implied_rate).
+    #[must_use]
+    pub fn equivalent_rate<E: DayCounter, T: DayCountDate>(
+        &self,
+        day_counter: E,
+        compounding: Compounding,
+        start: &T,
+        end: &T,
+    ) -> Option<InterestRate<E>> {
+        InterestRate::implied_rate(
+            self.compound_factor(start, end),
+            day_counter,
+            compounding,
+            start,
+            end,
+        )
+    }
+}
+
+/// Convert a rate quoted under the `from` convention into the equivalent rate
+/// under the `to` convention over the period between the two dates, e.g. an
+/// Actual/360 money market rate into its Actual/365 (Fixed) equivalent.
+///
+/// Both rates have the given `compounding`, and accrue to the same amount over
+/// the period. For simple rates, this is
+/// $$r_{to} = r_{from} \times \frac{t_{from}}{t_{to}}$$
+/// where $t$ are the day count fractions of the period under each convention.
+///
+/// Returns [`None`] if the period has no length under the `to` convention.
+#[must_use]
+pub fn convert_rate<S: DayCounter, D: DayCounter, T: DayCountDate>(
+    rate: f64,
+    from: S,
+    to: D,
+    start: &T,
+    end: &T,
+    compounding: Compounding,
+) -> Option<f64> {
+    if compounding == Compounding::Simple {
+        let time_from = from.day_count_fraction(start, end).get_fraction();
+        let time_to = to.day_count_fraction(start, end).get_fraction();
+        return (time_to != 0.0).then(|| rate * time_from / time_to);
+    }
+    InterestRate::new(rate, from, compounding)
+        .equivalent_rate(to, compounding, start, end)
+        .map(|rate| rate.rate())
+}
+
+impl<D> std::fmt::Display for InterestRate<D>
+where
+    D: DayCounter,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}% {} {}",
+            self.rate * 100.0,
+            self.day_counter,
+            self.compounding
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{convert_rate, Compounding, InterestRate};
+    use crate::{Actual360, Actual365Fixed, ActualActualISDA, DayCountDate, Frequency, Thirty360};
+
+    fn compound_and_discount_factors<T: DayCountDate>() {
+        let day1 = T::from_ymd(2024, 1, 1);
+        let day2 = T::from_ymd(2024, 7, 1);
+
+        let simple = InterestRate::new(0.05, Actual360, Compounding::Simple);
+        assert!(
+            (simple.compound_factor(&day1, &day2) - (1.0 + 0.05 * 182.0 / 360.0)).abs() < 1e-12
+        );
+        assert!(
+            (simple.discount_factor(&day1, &day2) * simple.compound_factor(&day1, &day2) - 1.0)
+                .abs()
+                < 1e-12
+        );
+
+        let compounded = InterestRate::new(
+            0.05,
+            Thirty360,
+            Compounding::Compounded(Frequency::SemiAnnual),
+        );
+        assert!((compounded.compound_factor(&day1, &day2) - 1.025).abs() < 1e-12);
+
+        let continuous = InterestRate::new(0.05, Actual365Fixed, Compounding::Continuous);
+        assert!(
+            (continuous.compound_factor(&day1, &day2) - (0.05 * 182.0 / 365.0_f64).exp()).abs()
+                < 1e-12
+        );
+        assert_eq!(continuous.to_string(), "5% Actual/365 (Fixed) Continuous");
+    }
+
+    fn implied_and_equivalent_rates<T: DayCountDate>() {
+        let day1 = T::from_ymd(2024, 1, 1);
+        let day2 = T::from_ymd(2025, 1, 1);
+
+        let rate =
+            InterestRate::implied_rate(1.05, Actual360, Compounding::Simple, &day1, &day2).unwrap();
+        assert!((rate.rate() - 0.05 * 360.0 / 366.0).abs() < 1e-12);
+        assert!(
+            InterestRate::implied_rate(1.05, Actual360, Compounding::Simple, &day1, &day1)
+                .is_none()
+        );
+
+        let rate = InterestRate::new(0.05, Actual365Fixed, Compounding::Continuous);
+        let equivalent = rate
+            .equivalent_rate(
+                Thirty360,
+                Compounding::Compounded(Frequency::Annual),
+                &day1,
+                &day2,
+            )
+            .unwrap();
+        assert_eq!(equivalent.day_counter(), &Thirty360);
+        assert!((equivalent.rate() - ((0.05 * 366.0 / 365.0_f64).exp() - 1.0)).abs() < 1e-12);
+        assert!(
+            (equivalent.compound_factor(&day1, &day2) - rate.compound_factor(&day1, &day2)).abs()
+                < 1e-12
+        );
+    }
+
+    fn convert_rates<T: DayCountDate>() {
+        let day1 = T::from_ymd(2024, 1, 1);
+        let day2 = T::from_ymd(2024, 7, 1);
+
+        let rate = convert_rate(
+            0.05,
+            Actual360,
+            Actual365Fixed,
+            &day1,
+            &day2,
+            Compounding::Simple,
+        )
+        .unwrap();
+        assert!((rate - 0.05 * 365.0 / 360.0).abs() < 1e-12);
+
+        // Across February 29th, the Actual/Actual (ISDA) basis is 366 days.
+        let rate = convert_rate(
+            0.05,
+            Actual360,
+            ActualActualISDA,
+            &day1,
+            &day2,
+            Compounding::Simple,
+        )
+        .unwrap();
+        assert!((rate - 0.05 * 366.0 / 360.0).abs() < 1e-12);
+
+        // 30/360 counts 180 days where Actual/360 counts 182.
+        let rate = convert_rate(
+            0.04,
+            Thirty360,
+            Actual360,
+            &day1,
+            &day2,
+            Compounding::Compounded(Frequency::SemiAnnual),
+        )
+        .unwrap();
+        let compound_factor: f64 = 1.02;
+        assert!((rate - 2.0 * (compound_factor.powf(360.0 / (2.0 * 182.0)) - 1.0)).abs() < 1e-12);
+
+        assert!(convert_rate(
+            0.05,
+            Actual360,
+            Actual365Fixed,
+            &day1,
+            &day1,
+            Compounding::Continuous
+        )
+        .is_none());
+    }
+
+    test_date_backends!(
+        compound_and_discount_factors,
+        implied_and_equivalent_rates,
+        convert_rates
+    );
+}
@@ -0,0 +1,486 @@
+use crate::{
+    Actual360, Actual360Inc, Actual364, Actual36525, Actual36525Inc, Actual365A, Actual365Canadian,
+    Actual365Fixed, Actual365L, Actual366, Actual366Inc, ActualActualAFB, ActualActualICMA,
+    ActualActualISDA, CivilDate, DayCountDate, DayCountFraction, DayCounter, NL365,
+};
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, NaiveDateTime, Offset, TimeZone, Timelike};
+#[cfg(feature = "hifitime")]
+use hifitime::Epoch;
+#[cfg(feature = "jiff")]
+use jiff::{civil, Zoned};
+#[cfg(feature = "time")]
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// The [`DayCountDateTime`] trait represents date-times, which can be turned
+/// into dates for the day counters with an [`IntradayPolicy`].
+///
+/// It is implemented for [`CivilDate`] and [`CivilDateTime`], and with the
+/// corresponding features for `chrono::NaiveDateTime`, `chrono::DateTime`,
+/// `hifitime::Epoch`, `time::PrimitiveDateTime`, `time::OffsetDateTime`,
+/// `jiff::civil::DateTime` and `jiff::Zoned`.
+/// Date-times with a time zone or offset use their local date and time, while
+/// fractional days are measured between their UTC instants.
+pub trait DayCountDateTime {
+    /// The calendar date of the date-time.
+    fn date(&self) -> CivilDate;
+
+    /// The number of seconds elapsed since midnight.
+    fn seconds_of_day(&self) -> f64;
+
+    /// The offset of the local time from UTC, in seconds (e.g. `-18000.0` for
+    /// UTC-05:00). It is zero for date-times without a time zone.
+    fn utc_offset(&self) -> f64 {
+        0.0
+    }
+}
+
+/// A calendar date with a time of day, used by the day counters to measure
+/// periods in fractional days.
+///
+/// The time of day is stored as the number of seconds since midnight.
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+pub struct CivilDateTime {
+    date: CivilDate,
+    seconds: f64,
+}
+
+impl CivilDateTime {
+    /// Create a new [`CivilDateTime`] from a date and the number of seconds
+    /// since midnight.
+    #[must_use]
+    pub const fn new(date: CivilDate, seconds: f64) -> Self {
+        Self { date, seconds }
+    }
+
+    /// The calendar date.
+    #[must_use]
+    pub const fn date(&self) -> CivilDate {
+        self.date
+    }
+
+    /// The number of seconds since midnight.
+    #[must_use]
+    pub const fn seconds(&self) -> f64 {
+        self.seconds
+    }
+}
+
+impl DayCountDate for CivilDateTime {
+    fn year(&self) -> i32 {
+        self.date.year()
+    }
+    fn month(&self) -> u32 {
+        self.date.month()
+    }
+    fn day(&self) -> u32 {
+        self.date.day()
+    }
+    fn from_ymd(year: i32, month: u32, day: u32) -> Self {
+        Self::new(CivilDate::from_ymd(year, month, day), 0.0)
+    }
+    fn days_until(&self, end: &Self) -> f64 {
+        self.date.days_until(&end.date) + (end.seconds - self.seconds) / SECONDS_PER_DAY
+    }
+    fn to_civil(&self) -> CivilDate {
+        self.date
+    }
+}
+
+/// How the time of day of date-times is taken into account by the
+/// [Actual family](ActualDayCounter) of day counters.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum IntradayPolicy {
+    /// The time of day is ignored, and only whole days are counted.
+    #[default]
+    Truncate,
+    /// The elapsed time is counted as fractional actual days. For date-times
+    /// with a time zone or offset, it is measured between their UTC instants,
+    /// so that different offsets and daylight saving time changes are taken
+    /// into account.
+    Fractional,
+    /// Date-times at or after the cutoff, given in seconds since midnight,
+    /// count as the next day. Only whole days are counted.
+    Cutoff(u32),
+}
+
+impl IntradayPolicy {
+    /// Create a new [`IntradayPolicy::Cutoff`] at the given time of day.
+    #[must_use]
+    pub const fn cutoff(hour: u32, minute: u32) -> Self {
+        Self::Cutoff(hour * 3600 + minute * 60)
+    }
+
+    /// Apply the policy to a date-time.
+    #[must_use]
+    pub fn apply<T: DayCountDateTime>(&self, date_time: &T) -> CivilDateTime {
+        let date = date_time.date();
+        let seconds = date_time.seconds_of_day();
+        match *self {
+            Self::Truncate => CivilDateTime::new(date, 0.0),
+            Self::Fractional => {
+                let seconds = seconds - date_time.utc_offset();
+                let days = (seconds / SECONDS_PER_DAY).floor();
+                CivilDateTime::new(date.add_days(days as i64), seconds - days * SECONDS_PER_DAY)
+            }
+            Self::Cutoff(cutoff) if seconds >= f64::from(cutoff) => {
+                CivilDateTime::new(date.add_days(1), 0.0)
+            }
+            Self::Cutoff(_) => CivilDateTime::new(date, 0.0),
+        }
+    }
+}
+
+/// The [`ActualDayCounter`] trait represents day counters whose numerator is
+/// the actual number of days between the two dates, which can therefore
+/// measure periods between date-times.
+pub trait ActualDayCounter: DayCounter {
+    /// Given two date-times, calculate the day-count-fraction between them,
+    /// taking their time of day into account according to `policy`.
+    fn day_count_fraction_intraday<T: DayCountDateTime>(
+        &self,
+        start: &T,
+        end: &T,
+        policy: IntradayPolicy,
+    ) -> DayCountFraction<Self> {
+        self.day_count_fraction(&policy.apply(start), &policy.apply(end))
+    }
+}
+
+impl ActualDayCounter for Actual360 {}
+impl ActualDayCounter for Actual360Inc {}
+impl ActualDayCounter for Actual364 {}
+impl ActualDayCounter for Actual365A {}
+impl ActualDayCounter for Actual365Canadian {}
+impl ActualDayCounter for Actual365Fixed {}
+impl ActualDayCounter for Actual365L {}
+impl ActualDayCounter for Actual366 {}
+impl ActualDayCounter for Actual366Inc {}
+impl ActualDayCounter for Actual36525 {}
+impl ActualDayCounter for Actual36525Inc {}
+impl ActualDayCounter for ActualActualAFB {}
+impl ActualDayCounter for ActualActualICMA {}
+impl ActualDayCounter for ActualActualISDA {}
+impl ActualDayCounter for NL365 {}
+
+#[cfg(any(
+    feature = "chrono",
+    feature = "hifitime",
+    feature = "time",
+    feature = "jiff"
+))]
+fn hms_to_seconds(hour: u32, minute: u32, second: u32, nanosecond: u32) -> f64 {
+    f64::from(hour * 3600 + minute * 60 + second) + f64::from(nanosecond) / 1e9
+}
+
+impl DayCountDateTime for CivilDate {
+    fn date(&self) -> CivilDate {
+        *self
+    }
+    fn seconds_of_day(&self) -> f64 {
+        0.0
+    }
+}
+
+impl DayCountDateTime for CivilDateTime {
+    fn date(&self) -> CivilDate {
+        self.date
+    }
+    fn seconds_of_day(&self) -> f64 {
+        self.seconds
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl DayCountDateTime for NaiveDateTime {
+    fn date(&self) -> CivilDate {
+        Self::date(self).to_civil()
+    }
+    fn seconds_of_day(&self) -> f64 {
+        hms_to_seconds(0, 0, self.num_seconds_from_midnight(), self.nanosecond())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: TimeZone> DayCountDateTime for DateTime<Tz> {
+    fn date(&self) -> CivilDate {
+        self.naive_local().date().to_civil()
+    }
+    fn seconds_of_day(&self) -> f64 {
+        self.naive_local().seconds_of_day()
+    }
+    fn utc_offset(&self) -> f64 {
+        f64::from(self.offset().fix().local_minus_utc())
+    }
+}
+
+#[cfg(feature = "hifitime")]
+impl DayCountDateTime for Epoch {
+    fn date(&self) -> CivilDate {
+        self.to_civil()
+    }
+    fn seconds_of_day(&self) -> f64 {
+        let (_, _, _, hour, minute, second, nanosecond) = self.to_gregorian_utc();
+        hms_to_seconds(
+            u32::from(hour),
+            u32::from(minute),
+            u32::from(second),
+            nanosecond,
+        )
+    }
+}
+
+#[cfg(feature = "time")]
+impl DayCountDateTime for PrimitiveDateTime {
+    fn date(&self) -> CivilDate {
+        Self::date(*self).to_civil()
+    }
+    fn seconds_of_day(&self) -> f64 {
+        let (hour, minute, second, nanosecond) = self.as_hms_nano();
+        hms_to_seconds(
+            u32::from(hour),
+            u32::from(minute),
+            u32::from(second),
+            nanosecond,
+        )
+    }
+}
+
+#[cfg(feature = "time")]
+impl DayCountDateTime for OffsetDateTime {
+    fn date(&self) -> CivilDate {
+        Self::date(*self).to_civil()
+    }
+    fn seconds_of_day(&self) -> f64 {
+        let (hour, minute, second, nanosecond) = self.to_hms_nano();
+        hms_to_seconds(
+            u32::from(hour),
+            u32::from(minute),
+            u32::from(second),
+            nanosecond,
+        )
+    }
+    fn utc_offset(&self) -> f64 {
+        f64::from(self.offset().whole_seconds())
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl DayCountDateTime for civil::DateTime {
+    fn date(&self) -> CivilDate {
+        Self::date(*self).to_civil()
+    }
+    #[allow(clippy::cast_sign_loss)]
+    fn seconds_of_day(&self) -> f64 {
+        hms_to_seconds(
+            self.hour() as u32,
+            self.minute() as u32,
+            self.second() as u32,
+            self.subsec_nanosecond() as u32,
+        )
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl DayCountDateTime for Zoned {
+    fn date(&self) -> CivilDate {
+        self.datetime().date().to_civil()
+    }
+    fn seconds_of_day(&self) -> f64 {
+        self.datetime().seconds_of_day()
+    }
+    fn utc_offset(&self) -> f64 {
+        f64::from(self.offset().seconds())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Actual360, Actual365Canadian, Actual365Fixed, ActualActualAFB, ActualActualICMA,
+        ActualActualISDA, ActualDayCounter, CivilDate, CivilDateTime, DayCountDateTime, DayCounter,
+        Frequency, IntradayPolicy, NL365,
+    };
+
+    fn date_time(year: i32, month: u32, day: u32, hour: u32) -> CivilDateTime {
+        let date = CivilDate::from_ymd_opt(year, month, day).unwrap();
+        CivilDateTime::new(date, f64::from(hour * 3600))
+    }
+
+    #[test]
+    fn intraday_conventions() {
+        let fractional = IntradayPolicy::Fractional;
+
+        // Whole years are stepped back from the end, keeping its time of day.
+        let start = date_time(2023, 3, 1, 12);
+        let end = date_time(2025, 3, 1, 18);
+        let yf = ActualActualAFB.day_count_fraction_intraday(&start, &end, fractional);
+        assert!((yf.get_fraction() - (2.0 + 0.25 / 365.0)).abs() < 1e-12);
+
+        // The period runs half a day into the next, 184-day, coupon period.
+        let dc = ActualActualICMA::new(
+            CivilDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            CivilDate::from_ymd_opt(2024, 7, 1).unwrap(),
+            Frequency::SemiAnnual,
+        )
+        .unwrap();
+        let start = date_time(2024, 1, 1, 0);
+        let end = date_time(2024, 7, 1, 12);
+        let yf = dc.day_count_fraction_intraday(&start, &end, fractional);
+        assert!((yf.get_fraction() - (0.5 + 0.5 / 368.0)).abs() < 1e-12);
+
+        let dc = Actual365Canadian::new(
+            CivilDate::from_ymd_opt(2023, 6, 1).unwrap(),
+            CivilDate::from_ymd_opt(2023, 12, 1).unwrap(),
+            Frequency::SemiAnnual,
+        )
+        .unwrap();
+        let start = date_time(2023, 6, 1, 0);
+        let end = date_time(2023, 6, 2, 12);
+        let yf = dc.day_count_fraction_intraday(&start, &end, fractional);
+        assert!((yf.get_fraction() - 1.5 / 365.0).abs() < 1e-12);
+
+        // February 29th is not counted.
+        let start = date_time(2024, 2, 28, 12);
+        let end = date_time(2024, 3, 1, 0);
+        let yf = NL365.day_count_fraction_intraday(&start, &end, fractional);
+        assert!((yf.get_fraction() - 0.5 / 365.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn intraday_policies() {
+        let start = date_time(2024, 1, 1, 18);
+        let end = date_time(2024, 1, 3, 6);
+
+        let yf = Actual365Fixed.day_count_fraction_intraday(&start, &end, IntradayPolicy::Truncate);
+        assert!((yf.get_fraction() - 2.0 / 365.0).abs() < 1e-12);
+
+        let yf =
+            Actual365Fixed.day_count_fraction_intraday(&start, &end, IntradayPolicy::Fractional);
+        assert!((yf.get_fraction() - 1.5 / 365.0).abs() < 1e-12);
+
+        let cutoff = IntradayPolicy::cutoff(17, 0);
+        let yf = Actual365Fixed.day_count_fraction_intraday(&start, &end, cutoff);
+        assert!((yf.get_fraction() - 1.0 / 365.0).abs() < 1e-12);
+
+        let start = date_time(2023, 12, 31, 12);
+        let end = date_time(2024, 1, 1, 12);
+        let yf =
+            ActualActualISDA.day_count_fraction_intraday(&start, &end, IntradayPolicy::Fractional);
+        assert!((yf.get_fraction() - (0.5 / 365.0 + 0.5 / 366.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn date_time_backends() {
+        let expected = date_time(2024, 1, 1, 18);
+        let check = |date_time: &dyn DayCountDateTime| {
+            assert_eq!(date_time.date(), expected.date());
+            assert!((date_time.seconds_of_day() - expected.seconds()).abs() < 1e-6);
+        };
+        check(&expected);
+
+        #[cfg(feature = "chrono")]
+        {
+            use chrono::{FixedOffset, NaiveDate, TimeZone};
+            let naive = NaiveDate::from_ymd_opt(2024, 1, 1)
+                .unwrap()
+                .and_hms_opt(18, 0, 0)
+                .unwrap();
+            check(&naive);
+            let offset = FixedOffset::west_opt(5 * 3600).unwrap();
+            check(&offset.from_local_datetime(&naive).unwrap());
+        }
+        #[cfg(feature = "hifitime")]
+        check(&hifitime::Epoch::from_gregorian_utc_hms(
+            2024, 1, 1, 18, 0, 0,
+        ));
+        #[cfg(feature = "time")]
+        {
+            use time::{Date, Month, Time};
+            let date = Date::from_calendar_date(2024, Month::January, 1).unwrap();
+            let primitive = date.with_time(Time::from_hms(18, 0, 0).unwrap());
+            check(&primitive);
+            check(&primitive.assume_offset(time::UtcOffset::from_hms(-5, 0, 0).unwrap()));
+        }
+        #[cfg(feature = "jiff")]
+        {
+            let civil = jiff::civil::date(2024, 1, 1).at(18, 0, 0, 0);
+            check(&civil);
+            check(
+                &civil
+                    .to_zoned(jiff::tz::TimeZone::fixed(jiff::tz::offset(-5)))
+                    .unwrap(),
+            );
+        }
+
+        let yf = Actual360.day_count_fraction(&expected, &date_time(2024, 1, 2, 6));
+        assert!((yf.get_fraction() - 0.5 / 360.0).abs() < 1e-12);
+    }
+
+    #[test]
+    #[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+    fn utc_offsets() {
+        let policy = IntradayPolicy::Fractional;
+
+        #[cfg(feature = "chrono")]
+        {
+            use chrono::{FixedOffset, NaiveDate, TimeZone};
+            let at = |day: u32, hour: u32, offset: i32| {
+                let naive = NaiveDate::from_ymd_opt(2024, 3, day)
+                    .unwrap()
+                    .and_hms_opt(hour, 0, 0)
+                    .unwrap();
+                let offset = FixedOffset::east_opt(offset * 3600).unwrap();
+                offset.from_local_datetime(&naive).unwrap()
+            };
+
+            // The same instant, written with two different offsets.
+            let yf =
+                Actual365Fixed.day_count_fraction_intraday(&at(1, 18, -5), &at(1, 23, 0), policy);
+            assert!(yf.get_fraction().abs() < 1e-12);
+
+            // A day on which daylight saving time starts lasts 23 hours.
+            let yf =
+                Actual365Fixed.day_count_fraction_intraday(&at(10, 0, -5), &at(11, 0, -4), policy);
+            assert!((yf.get_fraction() - 23.0 / 24.0 / 365.0).abs() < 1e-12);
+
+            // Whole days use the local dates.
+            let yf = Actual365Fixed.day_count_fraction_intraday(
+                &at(1, 23, 0),
+                &at(2, 18, -5),
+                IntradayPolicy::Truncate,
+            );
+            assert!((yf.get_fraction() - 1.0 / 365.0).abs() < 1e-12);
+        }
+        #[cfg(feature = "time")]
+        {
+            use time::{Date, Month, Time, UtcOffset};
+            let date = Date::from_calendar_date(2024, Month::March, 1).unwrap();
+            let start = date
+                .with_time(Time::from_hms(18, 0, 0).unwrap())
+                .assume_offset(UtcOffset::from_hms(-5, 0, 0).unwrap());
+            let end = date
+                .with_time(Time::from_hms(23, 0, 0).unwrap())
+                .assume_utc();
+            let yf = Actual365Fixed.day_count_fraction_intraday(&start, &end, policy);
+            assert!(yf.get_fraction().abs() < 1e-12);
+        }
+        #[cfg(feature = "jiff")]
+        {
+            use jiff::tz::{offset, TimeZone};
+            let start = jiff::civil::date(2024, 3, 1)
+                .at(18, 0, 0, 0)
+                .to_zoned(TimeZone::fixed(offset(-5)))
+                .unwrap();
+            let end = jiff::civil::date(2024, 3, 1)
+                .at(23, 0, 0, 0)
+                .to_zoned(TimeZone::UTC)
+                .unwrap();
+            let yf = Actual365Fixed.day_count_fraction_intraday(&start, &end, policy);
+            assert!(yf.get_fraction().abs() < 1e-12);
+        }
+    }
+}
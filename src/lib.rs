@@ -7,18 +7,73 @@
 //! - [Actual/360 (inc)](Actual360Inc)
 //! - [Actual/364](Actual364)
 //! - [Actual/365 (A)](Actual365A)
+//! - [Actual/365 (Canadian Bond)](Actual365Canadian)
 //! - [Actual/365 (Fixed)](Actual365Fixed)
+//! - [Actual/365L](Actual365L)
 //! - [Actual/366](Actual366)
 //! - [Actual/366 (inc)](Actual366Inc)
+//! - [Actual/Actual (AFB)](ActualActualAFB)
+//! - [Actual/Actual (ICMA)](ActualActualICMA)
+//! - [Actual/Actual (ISDA)](ActualActualISDA)
 //! - [Actual/365.25](Actual36525)
 //! - [Actual/365.25 (inc)](Actual36525Inc)
+//! - [Business/252](Business252)
 //! - [NL/365](NL365)
 //! - [1/1](OneOne)
 //! - [30/360](Thirty360)
+//! - [30/360 (German)](Thirty360German)
+//! - [30/360 (ICMA)](Thirty360ICMA)
+//! - [30/360 (US)](Thirty360US)
+//! - [30/365](Thirty365)
 //! - [30E/360](ThirtyE360)
 //! - [30E/360 (ISDA)](ThirtyE360ISDA)
 //! - [30E+/360 (ISDA)](ThirtyEPlus360ISDA)
 //!
+//! Every day counter accepts any [`DayCountDate`]: [`CivilDate`], and
+//! `chrono::NaiveDate`, `hifitime::Epoch`, `time::Date` and
+//! `jiff::civil::Date` with the `chrono` (enabled by default), `hifitime`,
+//! `time` and `jiff` features respectively. The features are additive, so
+//! several backends can be enabled at once. `jiff::Zoned` date-times can be
+//! used through their `date`.
+//!
+//! Periods between date-times (e.g. for time to expiry) are measured with the
+//! Actual family of day counters through [`ActualDayCounter`], with an
+//! [`IntradayPolicy`] deciding whether the time of day is ignored, counted as
+//! fractional days, or compared against a daily cutoff time. Other date types (e.g. integer
+//! serial dates) can be used by implementing [`DayCountDate`] for them.
+//!
+//! Besides the fraction itself, every day counter gives the number of days it
+//! counts and the number of days in its basis (e.g. 92/360) through
+//! [`DayCounter::day_count`] and [`DayCounter::days_in_basis`]. The adjusted
+//! dates of the 30/360 family are available through [`ThirtyDayCounter`].
+//! Conversely, [`DayCounter::date_from_fraction`] gives the earliest date at
+//! which a target fraction is reached.
+//!
+//! Fractions are also kept as exact ratios of whole numbers (e.g. 23/90 for
+//! 92/360), which can be converted to `num_rational::Ratio` and
+//! `rust_decimal::Decimal` with the `num-rational` and `decimal` features.
+//! With the `decimal` feature, `DayCounter::day_count_fraction_decimal` also
+//! gives the fraction rounded to a chosen precision, without going through
+//! [`f64`].
+//!
+//! An [`InterestRate`] pairs a rate with a day counter and its [`Compounding`],
+//! giving compound and discount factors between two dates, as well as implied
+//! and equivalent rates. Quoted rates can be converted from one convention
+//! to another over a period with [`convert_rate`].
+//!
+//! When the convention is only known at runtime (e.g. from trade data), any
+//! of the above can be wrapped in a [`DayCountConvention`]. Conventions without
+//! parameters can also be parsed from their names and common market aliases
+//! (e.g. `"ACT/360"`, `"30/360 BOND BASIS"`) through
+//! [`FromStr`](std::str::FromStr).
+//!
+//! Day counters reproducing Excel's `YEARFRAC` are available in the [`excel`]
+//! module, and FpML `dayCountFractionScheme` codes are mapped in the [`fpml`]
+//! module.
+//!
+//! With the `serde` feature, all conventions and [`DayCountFraction`] implement
+//! `Serialize` and `Deserialize`, using the convention names shown above.
+//!
 //! If there are any conventions that you would like implemented, don't
 //! hestitate to submit a PR or raise in issue on [GitHub](https://github.com/MeetThePatel/day_count_conventions.rs)!
 //!
@@ -36,32 +91,123 @@
 #![warn(missing_docs)]
 #![warn(rustdoc::broken_intra_doc_links)]
 
+/// Run generic test functions, taking the date type as their only type
+/// parameter, against every enabled date backend.
+#[cfg(test)]
+macro_rules! test_date_backends {
+    ($($name:ident),* $(,)?) => {
+        $(
+            mod $name {
+                #[test]
+                fn civil() {
+                    super::$name::<crate::CivilDate>();
+                }
+                #[cfg(feature = "chrono")]
+                #[test]
+                fn chrono() {
+                    super::$name::<chrono::NaiveDate>();
+                }
+                #[cfg(feature = "hifitime")]
+                #[test]
+                fn hifitime() {
+                    super::$name::<hifitime::Epoch>();
+                }
+                #[cfg(feature = "jiff")]
+                #[test]
+                fn jiff() {
+                    super::$name::<jiff::civil::Date>();
+                }
+                #[cfg(feature = "time")]
+                #[test]
+                fn time() {
+                    super::$name::<time::Date>();
+                }
+            }
+        )*
+    };
+}
+
 /// The [`DayCounter`] trait represents any day count conventions. Only one method
 /// is required: [`day_count_function`](DayCounter::day_count_fraction).
-pub trait DayCounter: std::fmt::Display + Default + Copy + Clone {
-    /// Given a two dates, calculate the day-count-fraction between the two dates.
-    #[cfg(not(feature = "hifitime"))]
-    fn day_count_fraction(
-        &self,
-        start: &chrono::NaiveDate,
-        end: &chrono::NaiveDate,
-    ) -> DayCountFraction<Self>;
+pub trait DayCounter: std::fmt::Display + Default + Clone {
     /// Given a two dates, calculate the day-count-fraction between the two dates.
-    #[cfg(feature = "hifitime")]
-    fn day_count_fraction(
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self>;
+
+    /// Given two dates, calculate the number of days counted by the convention
+    /// between the two dates, i.e. the numerator of the day-count-fraction
+    /// (e.g. 92 in 92/360). The time of day of date-times is ignored.
+    ///
+    /// The default implementation counts the actual number of days.
+    fn day_count<T: DayCountDate>(&self, start: &T, end: &T) -> i64 {
+        actual_days(start, end)
+    }
+
+    /// Given two dates, the number of days in the basis of the convention,
+    /// i.e. the denominator of the day-count-fraction (e.g. 360 in 92/360).
+    ///
+    /// When this is `Some`, the day-count-fraction is the
+    /// [`day_count`](DayCounter::day_count) divided by this denominator. It is
+    /// `None` when the fraction has no such form for the given dates, e.g. for
+    /// [Actual/Actual (ISDA)](ActualActualISDA) across several years.
+    fn days_in_basis<T: DayCountDate>(&self, _start: &T, _end: &T) -> Option<f64> {
+        None
+    }
+
+    /// Given a start date and a target day-count-fraction, the earliest date
+    /// at or after the start date whose day-count-fraction from the start date
+    /// reaches the target (e.g. the first date at least half a year after the
+    /// start date under Actual/365 (Fixed)).
+    ///
+    /// The default implementation searches the dates after the start date,
+    /// assuming that the fraction does not decrease as the end date moves
+    /// later. Conventions with a fixed basis (e.g. Actual/360 and the 30/360
+    /// family) instead compute the date from the target, and only check it
+    /// against the fraction of the neighbouring days to correct rounding.
+    ///
+    /// Returns [`None`] if the target is not finite, or is not reached within
+    /// about 1,000 years.
+    fn date_from_fraction<T: DayCountDate>(&self, start: &T, fraction: f64) -> Option<T> {
+        solve_date(self, start, fraction, estimate_days(fraction, 365.25))
+    }
+
+    /// Given two dates, calculate the day-count-fraction between the two dates
+    /// as a [`Decimal`](rust_decimal::Decimal) with `decimal_places` digits after
+    /// the decimal point, rounded with the given `strategy`.
+    ///
+    /// The fraction is computed from its exact ratio, without going through
+    /// [`f64`]. See [`DayCountFraction::to_decimal_rounded`].
+    #[cfg(feature = "decimal")]
+    fn day_count_fraction_decimal<T: DayCountDate>(
         &self,
-        start: &hifitime::Epoch,
-        end: &hifitime::Epoch,
-    ) -> DayCountFraction<Self>;
+        start: &T,
+        end: &T,
+        decimal_places: u32,
+        strategy: rust_decimal::RoundingStrategy,
+    ) -> Option<rust_decimal::Decimal> {
+        self.day_count_fraction(start, end)
+            .to_decimal_rounded(decimal_places, strategy)
+    }
 }
 
 /// Wrapper type for a day count fraction that contains information on which
 /// counter was used to generate the fraction.
+///
+/// Besides its [`f64`] value, the fraction carries an exact
+/// [`numerator`](DayCountFraction::numerator) and
+/// [`denominator`](DayCountFraction::denominator) whenever it is a ratio of
+/// whole numbers of days, which is the case for every convention unless the
+/// time of day of date-times is counted as fractional days.
+///
+/// Fractions of the same convention can be compared, added and subtracted
+/// (e.g. to sum the fractions of consecutive periods), and multiplied by a
+/// rate or a notional, which gives an [`f64`]. Fractions of different
+/// conventions cannot be mixed.
 pub struct DayCountFraction<D>
 where
     D: DayCounter,
 {
     fraction: f64,
+    ratio: Option<(i64, i64)>,
     _marker: std::marker::PhantomData<D>,
 }
 impl<D> DayCountFraction<D>
@@ -74,16 +220,141 @@ where
     pub const fn new(fraction: f64) -> Self {
         Self {
             fraction,
+            ratio: None,
             _marker: std::marker::PhantomData,
         }
     }
 
+    /// Create a new exact DayCountFraction with convention D, from a numerator
+    /// and a denominator. The ratio is stored in lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero, or if the ratio in lowest terms does
+    /// not fit in [`i64`] with a positive denominator (e.g. `1 / i64::MIN`).
+    #[must_use]
+    pub const fn from_ratio(numerator: i64, denominator: i64) -> Self {
+        assert!(denominator != 0, "denominator must not be zero");
+        match Self::checked_ratio(numerator, denominator) {
+            Some(fraction) => fraction,
+            None => panic!("ratio out of range"),
+        }
+    }
+
+    /// The ratio in lowest terms, if it fits in [`i64`] with a positive
+    /// denominator.
+    #[allow(clippy::cast_possible_wrap, clippy::cast_precision_loss)]
+    const fn checked_ratio(numerator: i64, denominator: i64) -> Option<Self> {
+        let divisor = gcd(numerator.unsigned_abs(), denominator.unsigned_abs());
+        let magnitude = numerator.unsigned_abs() / divisor;
+        let denominator_magnitude = denominator.unsigned_abs() / divisor;
+        let negative = (numerator < 0) != (denominator < 0);
+        if denominator_magnitude > i64::MAX as u64 || magnitude > i64::MAX as u64 + negative as u64
+        {
+            return None;
+        }
+        // A magnitude of 2^63 wraps to `i64::MIN`, which is its own negation.
+        let numerator = if negative {
+            (magnitude as i64).wrapping_neg()
+        } else {
+            magnitude as i64
+        };
+        let denominator = denominator_magnitude as i64;
+        Some(Self {
+            fraction: numerator as f64 / denominator as f64,
+            ratio: Some((numerator, denominator)),
+            _marker: std::marker::PhantomData,
+        })
+    }
+
+    /// Create a new exact DayCountFraction with convention D, whose [`f64`]
+    /// value is computed separately from its ratio (e.g. to reproduce the
+    /// floating-point arithmetic of another implementation).
+    pub(crate) fn with_value(fraction: f64, numerator: i64, denominator: i64) -> Self {
+        Self {
+            fraction,
+            ..Self::from_ratio(numerator, denominator)
+        }
+    }
+
     /// Access the fraction
     #[must_use]
     #[inline]
     pub const fn get_fraction(&self) -> f64 {
         self.fraction
     }
+
+    /// The numerator of the exact fraction, in lowest terms, if it is known.
+    #[must_use]
+    #[inline]
+    pub const fn numerator(&self) -> Option<i64> {
+        match self.ratio {
+            Some((numerator, _)) => Some(numerator),
+            None => None,
+        }
+    }
+
+    /// The (positive) denominator of the exact fraction, in lowest terms, if
+    /// it is known.
+    #[must_use]
+    #[inline]
+    pub const fn denominator(&self) -> Option<i64> {
+        match self.ratio {
+            Some((_, denominator)) => Some(denominator),
+            None => None,
+        }
+    }
+
+    /// The exact fraction as a [`Ratio`](num_rational::Ratio), if it is known.
+    #[cfg(feature = "num-rational")]
+    #[must_use]
+    pub fn to_ratio(&self) -> Option<num_rational::Ratio<i64>> {
+        self.ratio
+            .map(|(numerator, denominator)| num_rational::Ratio::new_raw(numerator, denominator))
+    }
+
+    /// The exact fraction as a [`Decimal`](rust_decimal::Decimal), if it is
+    /// known. The division is carried out to the full precision of
+    /// [`Decimal`](rust_decimal::Decimal), and [`None`] is returned on overflow.
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn to_decimal(&self) -> Option<rust_decimal::Decimal> {
+        let (numerator, denominator) = self.ratio?;
+        rust_decimal::Decimal::from(numerator).checked_div(rust_decimal::Decimal::from(denominator))
+    }
+
+    /// The exact fraction as a [`Decimal`](rust_decimal::Decimal) with
+    /// `decimal_places` digits after the decimal point, rounded with the
+    /// given `strategy`, if it is known.
+    ///
+    /// The rounding is carried out on the exact ratio, so the result is the
+    /// correctly rounded fraction (e.g. 1/8 rounds to 0.12 under
+    /// [`MidpointNearestEven`](rust_decimal::RoundingStrategy::MidpointNearestEven)).
+    /// [`None`] is returned if the result does not fit in a
+    /// [`Decimal`](rust_decimal::Decimal), e.g. if `decimal_places` exceeds 28.
+    #[cfg(feature = "decimal")]
+    #[must_use]
+    pub fn to_decimal_rounded(
+        &self,
+        decimal_places: u32,
+        strategy: rust_decimal::RoundingStrategy,
+    ) -> Option<rust_decimal::Decimal> {
+        let (numerator, denominator) = self.ratio?;
+        if decimal_places > rust_decimal::Decimal::MAX_SCALE {
+            return None;
+        }
+        let scaled = i128::from(numerator).checked_mul(10_i128.checked_pow(decimal_places)?)?;
+        let (quotient, remainder) = (
+            scaled / i128::from(denominator),
+            scaled % i128::from(denominator),
+        );
+        let rounded = if remainder == 0 {
+            quotient
+        } else {
+            round_quotient(quotient, remainder, i128::from(denominator), strategy)
+        };
+        rust_decimal::Decimal::try_from_i128_with_scale(rounded, decimal_places).ok()
+    }
 }
 impl<D> PartialEq for DayCountFraction<D>
 where
@@ -93,6 +364,198 @@ where
         self.fraction == other.fraction
     }
 }
+impl<D> PartialOrd for DayCountFraction<D>
+where
+    D: DayCounter,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.fraction.partial_cmp(&other.fraction)
+    }
+}
+impl<D> Clone for DayCountFraction<D>
+where
+    D: DayCounter,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<D> Copy for DayCountFraction<D> where D: DayCounter {}
+impl<D> std::fmt::Debug for DayCountFraction<D>
+where
+    D: DayCounter,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DayCountFraction")
+            .field("convention", &format_args!("{}", D::default()))
+            .field("fraction", &self.fraction)
+            .field("ratio", &self.ratio)
+            .finish()
+    }
+}
+/// Fractions are displayed as their [`f64`] value, following the formatting
+/// options (e.g. `{:.4}`).
+impl<D> std::fmt::Display for DayCountFraction<D>
+where
+    D: DayCounter,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.fraction, f)
+    }
+}
+impl<D> From<DayCountFraction<D>> for f64
+where
+    D: DayCounter,
+{
+    fn from(fraction: DayCountFraction<D>) -> Self {
+        fraction.fraction
+    }
+}
+
+/// The sum of two fractions of the same convention, e.g. over consecutive
+/// periods. It is exact if both fractions are exact and the sum does not
+/// overflow.
+impl<D> std::ops::Add for DayCountFraction<D>
+where
+    D: DayCounter,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let sum = self
+            .ratio
+            .zip(other.ratio)
+            .and_then(|((n1, d1), (n2, d2))| {
+                let numerator = n1.checked_mul(d2)?.checked_add(n2.checked_mul(d1)?)?;
+                Self::checked_ratio(numerator, d1.checked_mul(d2)?)
+            });
+        sum.unwrap_or_else(|| Self::new(self.fraction + other.fraction))
+    }
+}
+impl<D> std::ops::Neg for DayCountFraction<D>
+where
+    D: DayCounter,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        match self.ratio {
+            Some((numerator, denominator)) => Self::checked_ratio(numerator, -denominator)
+                .unwrap_or_else(|| Self::new(-self.fraction)),
+            None => Self::new(-self.fraction),
+        }
+    }
+}
+impl<D> std::ops::Sub for DayCountFraction<D>
+where
+    D: DayCounter,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+impl<D> std::ops::AddAssign for DayCountFraction<D>
+where
+    D: DayCounter,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+impl<D> std::ops::SubAssign for DayCountFraction<D>
+where
+    D: DayCounter,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+impl<D> std::iter::Sum for DayCountFraction<D>
+where
+    D: DayCounter,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::from_ratio(0, 1), |sum, fraction| sum + fraction)
+    }
+}
+impl<'a, D> std::iter::Sum<&'a Self> for DayCountFraction<D>
+where
+    D: DayCounter,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Scaling by a rate or a notional, e.g. `fraction * rate * notional` for the
+/// interest accrued over a period.
+impl<D> std::ops::Mul<f64> for DayCountFraction<D>
+where
+    D: DayCounter,
+{
+    type Output = f64;
+
+    fn mul(self, factor: f64) -> f64 {
+        self.fraction * factor
+    }
+}
+impl<D> std::ops::Mul<DayCountFraction<D>> for f64
+where
+    D: DayCounter,
+{
+    type Output = Self;
+
+    fn mul(self, fraction: DayCountFraction<D>) -> Self {
+        self * fraction.fraction
+    }
+}
+
+/// Round the quotient `quotient` of a division, truncated towards zero, given
+/// its non-zero `remainder` and the (positive) `denominator`.
+#[cfg(feature = "decimal")]
+#[allow(deprecated)]
+fn round_quotient(
+    quotient: i128,
+    remainder: i128,
+    denominator: i128,
+    strategy: rust_decimal::RoundingStrategy,
+) -> i128 {
+    use rust_decimal::RoundingStrategy::{
+        AwayFromZero, BankersRounding, MidpointAwayFromZero, MidpointNearestEven,
+        MidpointTowardZero, RoundDown, RoundHalfDown, RoundHalfUp, RoundUp, ToNegativeInfinity,
+        ToPositiveInfinity, ToZero,
+    };
+    use std::cmp::Ordering;
+
+    let negative = remainder < 0;
+    let away_from_zero = if negative { quotient - 1 } else { quotient + 1 };
+    let midpoint = (2 * remainder.abs()).cmp(&denominator);
+    let round_away = match strategy {
+        MidpointNearestEven | BankersRounding => {
+            midpoint == Ordering::Greater || (midpoint == Ordering::Equal && quotient % 2 != 0)
+        }
+        MidpointAwayFromZero | RoundHalfUp => midpoint != Ordering::Less,
+        MidpointTowardZero | RoundHalfDown => midpoint == Ordering::Greater,
+        ToZero | RoundDown => false,
+        AwayFromZero | RoundUp => true,
+        ToNegativeInfinity => negative,
+        ToPositiveInfinity => !negative,
+    };
+    if round_away {
+        away_from_zero
+    } else {
+        quotient
+    }
+}
+
+const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
 
 mod actual_360;
 pub use actual_360::{Actual360, Actual360Inc};
@@ -101,7 +564,7 @@ mod actual_364;
 pub use actual_364::Actual364;
 
 mod actual_365;
-pub use actual_365::{Actual365A, Actual365Fixed};
+pub use actual_365::{Actual365A, Actual365Canadian, Actual365Fixed, Actual365L};
 
 mod actual_366;
 pub use actual_366::{Actual366, Actual366Inc};
@@ -109,6 +572,15 @@ pub use actual_366::{Actual366, Actual366Inc};
 mod actual_365_25;
 pub use actual_365_25::{Actual36525, Actual36525Inc};
 
+mod actual_actual;
+pub use actual_actual::{ActualActualAFB, ActualActualICMA, ActualActualISDA};
+
+mod business_252;
+pub use business_252::Business252;
+
+mod calendar;
+pub use calendar::{HolidayCalendar, HolidayList, WeekendsOnly};
+
 mod nl_365;
 pub use nl_365::NL365;
 
@@ -116,28 +588,57 @@ mod one_1;
 pub use one_1::OneOne;
 
 mod thirty_360;
-pub use thirty_360::{Thirty360, ThirtyE360, ThirtyE360ISDA, ThirtyEPlus360ISDA};
+pub use thirty_360::{
+    AdjustedDates, Thirty360, Thirty360German, Thirty360ICMA, Thirty360US, Thirty365,
+    ThirtyDayCounter, ThirtyE360, ThirtyE360ISDA, ThirtyEPlus360ISDA,
+};
+
+mod convention;
+pub use convention::{ConventionMismatchError, DayCountConvention};
+
+pub mod excel;
+
+pub mod fpml;
+
+mod parse;
+pub use parse::ParseConventionError;
+
+mod frequency;
+pub use frequency::Frequency;
+
+mod interest_rate;
+pub use interest_rate::{convert_rate, Compounding, InterestRate};
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+mod date;
+pub use date::{CivilDate, DayCountDate};
+
+mod intraday;
+pub use intraday::{ActualDayCounter, CivilDateTime, DayCountDateTime, IntradayPolicy};
 
 mod util;
-pub(crate) use util::{get_last_day_of_month, is_feb29_between_exc_inc, is_last_day_of_feb};
+pub(crate) use util::{
+    actual_days, actual_fraction, add_months, date_from_days, estimate_days, estimate_thirty_days,
+    get_days_in_year, get_last_day_of_month, is_feb29_between_exc_inc, is_last_day_of_feb,
+    is_leap_year, solve_date,
+};
 
 #[cfg(test)]
 mod tests {
-    use crate::{DayCounter, OneOne, NL365};
+    use crate::{
+        Actual360, Actual360Inc, Actual36525, Actual365Canadian, Actual365Fixed, ActualActualISDA,
+        ActualDayCounter, CivilDate, CivilDateTime, DayCountConvention, DayCountDate,
+        DayCountFraction, DayCounter, Frequency, IntradayPolicy, OneOne, Thirty360, NL365,
+    };
 
-    #[cfg(not(feature = "hifitime"))]
-    use chrono::NaiveDate;
-    #[cfg(feature = "hifitime")]
-    use hifitime::Epoch;
-
-    #[cfg(not(feature = "hifitime"))]
-    #[test]
-    fn comparison() {
-        let day1 = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
-        let day2 = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+    fn comparison<T: DayCountDate>() {
+        let day1 = T::from_ymd(2024, 1, 1);
+        let day2 = T::from_ymd(2024, 7, 1);
 
-        let dc1 = NL365::default();
-        let dc2 = OneOne::default();
+        let dc1 = NL365;
+        let dc2 = OneOne;
 
         let yf1 = dc1.day_count_fraction(&day1, &day2);
         let yf2 = dc2.day_count_fraction(&day1, &day2);
@@ -151,25 +652,219 @@ mod tests {
         // fraction.
         assert_ne!(yf1.fraction, yf2.fraction);
     }
-    #[cfg(feature = "hifitime")]
+
+    fn day_count_and_basis<T: DayCountDate>() {
+        let day1 = T::from_ymd(2024, 1, 1);
+        let day2 = T::from_ymd(2024, 4, 2);
+        let day3 = T::from_ymd(2025, 4, 2);
+
+        assert_eq!(Actual360.day_count(&day1, &day2), 92);
+        assert_eq!(Actual360.days_in_basis(&day1, &day2), Some(360.0));
+        assert_eq!(Actual360Inc.day_count(&day1, &day2), 93);
+        assert_eq!(NL365.day_count(&day1, &day2), 91);
+
+        assert_eq!(ActualActualISDA.day_count(&day1, &day3), 457);
+        assert_eq!(ActualActualISDA.days_in_basis(&day1, &day2), Some(366.0));
+        assert_eq!(ActualActualISDA.days_in_basis(&day1, &day3), None);
+
+        let convention = DayCountConvention::from(NL365);
+        assert_eq!(convention.day_count(&day1, &day2), 91);
+        assert_eq!(convention.days_in_basis(&day1, &day2), Some(365.0));
+    }
+
+    fn exact_fractions<T: DayCountDate>() {
+        let day1 = T::from_ymd(2023, 7, 1);
+        let day2 = T::from_ymd(2024, 7, 1);
+
+        let yf = Actual36525.day_count_fraction(&day1, &day2);
+        assert_eq!((yf.numerator(), yf.denominator()), (Some(488), Some(487)));
+
+        let yf = ActualActualISDA.day_count_fraction(&day2, &day1);
+        assert_eq!(yf.numerator(), Some(-(184 * 366 + 182 * 365) / 2));
+        assert_eq!(yf.denominator(), Some(365 * 366 / 2));
+        assert!((f64::from(yf) + 184.0 / 365.0 + 182.0 / 366.0).abs() < 1e-12);
+
+        #[cfg(feature = "num-rational")]
+        assert_eq!(
+            Actual360.day_count_fraction(&day1, &day2).to_ratio(),
+            Some(num_rational::Ratio::new(366, 360))
+        );
+        #[cfg(feature = "decimal")]
+        assert_eq!(
+            Actual360.day_count_fraction(&day1, &day2).to_decimal(),
+            Some("1.0166666666666666666666666667".parse().unwrap())
+        );
+
+        #[cfg(feature = "decimal")]
+        {
+            use crate::Actual364;
+            use rust_decimal::RoundingStrategy::{
+                MidpointAwayFromZero, MidpointNearestEven, ToPositiveInfinity, ToZero,
+            };
+
+            assert_eq!(
+                Actual36525.day_count_fraction_decimal(&day1, &day2, 12, MidpointNearestEven),
+                Some("1.002053388090".parse().unwrap())
+            );
+            assert_eq!(
+                Actual364.day_count_fraction_decimal(&day1, &day2, 15, ToZero),
+                Some("1.005494505494505".parse().unwrap())
+            );
+
+            // 45/360 = 0.125 lies exactly between 0.12 and 0.13.
+            let day3 = T::from_ymd(2023, 8, 15);
+            assert_eq!(
+                Actual360.day_count_fraction_decimal(&day1, &day3, 2, MidpointNearestEven),
+                Some("0.12".parse().unwrap())
+            );
+            assert_eq!(
+                Actual360.day_count_fraction_decimal(&day3, &day1, 2, MidpointAwayFromZero),
+                Some("-0.13".parse().unwrap())
+            );
+            assert_eq!(
+                Actual360.day_count_fraction_decimal(&day3, &day1, 2, ToPositiveInfinity),
+                Some("-0.12".parse().unwrap())
+            );
+
+            let day4 = T::from_ymd(2024, 1, 1);
+            let day5 = T::from_ymd(2024, 4, 2);
+            assert_eq!(
+                Actual360.day_count_fraction_decimal(&day4, &day5, 28, MidpointNearestEven),
+                Some("0.2555555555555555555555555556".parse().unwrap())
+            );
+            assert_eq!(
+                Actual360.day_count_fraction_decimal(&day4, &day5, 29, MidpointNearestEven),
+                None
+            );
+            assert_eq!(
+                DayCountConvention::from(Actual360).day_count_fraction_decimal(
+                    &day1,
+                    &day3,
+                    2,
+                    MidpointAwayFromZero
+                ),
+                Some("0.13".parse().unwrap())
+            );
+        }
+    }
+
+    fn fraction_arithmetic<T: DayCountDate>() {
+        let day1 = T::from_ymd(2024, 1, 1);
+        let day2 = T::from_ymd(2024, 4, 1);
+        let day3 = T::from_ymd(2024, 7, 1);
+
+        let first = Actual360.day_count_fraction(&day1, &day2);
+        let second = Actual360.day_count_fraction(&day2, &day3);
+        let whole = Actual360.day_count_fraction(&day1, &day3);
+
+        assert_eq!(first + second, whole);
+        assert_eq!(whole - second, first);
+        assert_eq!(-first, Actual360.day_count_fraction(&day2, &day1));
+        assert_eq!([first, second].iter().sum::<DayCountFraction<_>>(), whole);
+        assert_eq!((first + second).numerator(), Some(91));
+        assert!(first <= second && second < whole);
+
+        let mut total = first;
+        total += second;
+        total -= first;
+        assert_eq!(total, second);
+
+        assert!((first * 0.05 * 1_000_000.0 - 12_638.888_888_888_89).abs() < 1e-6);
+        assert!((0.05 * first - 0.05 * 91.0 / 360.0).abs() < 1e-12);
+        assert_eq!(format!("{whole:.4}"), "0.5056");
+    }
+
+    fn date_from_fraction<T: DayCountDate>() {
+        let start = T::from_ymd(2024, 1, 1);
+
+        // 182.5 days are reached on the 183rd day.
+        let end = Actual365Fixed.date_from_fraction(&start, 0.5).unwrap();
+        assert!(end == T::from_ymd(2024, 7, 2));
+
+        let end = Actual360.date_from_fraction(&start, 92.0 / 360.0).unwrap();
+        assert!(end == T::from_ymd(2024, 4, 2));
+        let end = Actual360Inc
+            .date_from_fraction(&start, 92.0 / 360.0)
+            .unwrap();
+        assert!(end == T::from_ymd(2024, 4, 1));
+
+        // The 31st is counted as the 30th, so a year ends on the 30th.
+        let day = T::from_ymd(2024, 1, 31);
+        let end = Thirty360.date_from_fraction(&day, 1.0).unwrap();
+        assert!(end == T::from_ymd(2025, 1, 30));
+        let day = T::from_ymd(2024, 1, 30);
+        let end = Thirty360.date_from_fraction(&day, 1.0 / 12.0).unwrap();
+        assert!(end == T::from_ymd(2024, 3, 1));
+
+        // Search-based default.
+        let end = ActualActualISDA.date_from_fraction(&start, 1.0).unwrap();
+        assert!(end == T::from_ymd(2025, 1, 1));
+        let end = DayCountConvention::from(NL365)
+            .date_from_fraction(&start, 59.0 / 365.0)
+            .unwrap();
+        assert!(end == T::from_ymd(2024, 3, 1));
+
+        // The Canadian fraction drops once a full coupon period is reached.
+        let dc = Actual365Canadian::new(
+            T::from_ymd(2023, 6, 1),
+            T::from_ymd(2023, 12, 2),
+            Frequency::SemiAnnual,
+        )
+        .unwrap();
+        let day = T::from_ymd(2023, 6, 1);
+        assert!(dc.date_from_fraction(&day, 0.4985).unwrap() == T::from_ymd(2023, 11, 30));
+        assert!(dc.date_from_fraction(&day, 0.499).unwrap() == T::from_ymd(2023, 12, 2));
+
+        assert!(Actual360.date_from_fraction(&start, -1.0).unwrap() == start);
+        assert!(OneOne.date_from_fraction(&start, 1.0).unwrap() == start);
+        assert!(OneOne.date_from_fraction(&start, 2.0).is_none());
+        assert!(Actual360.date_from_fraction(&start, f64::NAN).is_none());
+    }
+
     #[test]
-    fn comparison() {
-        let day1 = Epoch::from_gregorian_utc_at_midnight(2024, 1, 1);
-        let day2 = Epoch::from_gregorian_utc_at_midnight(2024, 7, 1);
+    fn extreme_ratios() {
+        let yf = DayCountFraction::<Actual360>::from_ratio(i64::MIN, 2);
+        assert_eq!(
+            (yf.numerator(), yf.denominator()),
+            (Some(i64::MIN / 2), Some(1))
+        );
+        let yf = DayCountFraction::<Actual360>::from_ratio(i64::MIN, i64::MIN);
+        assert_eq!((yf.numerator(), yf.denominator()), (Some(1), Some(1)));
+        let yf = DayCountFraction::<Actual360>::from_ratio(i64::MIN, 1);
+        assert_eq!(
+            (yf.numerator(), yf.denominator()),
+            (Some(i64::MIN), Some(1))
+        );
+        assert!(
+            std::panic::catch_unwind(|| DayCountFraction::<Actual360>::from_ratio(1, i64::MIN))
+                .is_err()
+        );
 
-        let dc1 = NL365;
-        let dc2 = OneOne;
+        // Negating i64::MIN overflows, so the result is no longer exact.
+        let yf = -DayCountFraction::<Actual360>::from_ratio(i64::MIN, 1);
+        assert_eq!(yf.numerator(), None);
+        assert!((yf.get_fraction() + i64::MIN as f64).abs() < 1.0);
+    }
 
-        let yf1 = dc1.day_count_fraction(&day1, &day2);
-        let yf2 = dc2.day_count_fraction(&day1, &day2);
+    #[test]
+    fn inexact_fractions() {
+        let date = CivilDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let start = CivilDateTime::new(date, 0.0);
+        let end = CivilDateTime::new(date.add_days(1), 43_200.0);
 
-        // The following line of code should not compile, as they come from
-        // two different day count conventions.
-        // yf1.eq(yf2);
+        let yf = Actual360.day_count_fraction_intraday(&start, &end, IntradayPolicy::Fractional);
+        assert_eq!(yf.numerator(), None);
+        assert!((yf.get_fraction() - 1.5 / 360.0).abs() < 1e-12);
 
-        // However, should it be the case that you *really* need to compare two
-        // year fractions of different conventions, you can always get the
-        // fraction.
-        assert_ne!(yf1.fraction, yf2.fraction);
+        let yf = Actual360.day_count_fraction_intraday(&start, &end, IntradayPolicy::Truncate);
+        assert_eq!((yf.numerator(), yf.denominator()), (Some(1), Some(360)));
     }
+
+    test_date_backends!(
+        comparison,
+        day_count_and_basis,
+        exact_fractions,
+        fraction_arithmetic,
+        date_from_fraction
+    );
 }
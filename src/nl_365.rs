@@ -1,9 +1,7 @@
-use crate::{is_feb29_between_exc_inc, DayCountFraction, DayCounter};
-
-#[cfg(not(feature = "hifitime"))]
-use chrono::NaiveDate;
-#[cfg(feature = "hifitime")]
-use hifitime::{Epoch, Unit};
+use crate::{
+    actual_days, actual_fraction, is_feb29_between_exc_inc, DayCountDate, DayCountFraction,
+    DayCounter,
+};
 
 /// NL/365
 ///
@@ -19,25 +17,27 @@ use hifitime::{Epoch, Unit};
 pub struct NL365;
 
 impl DayCounter for NL365 {
-    #[cfg(not(feature = "hifitime"))]
-    fn day_count_fraction(&self, start: &NaiveDate, end: &NaiveDate) -> DayCountFraction<Self> {
-        let mut numerator = (*end - *start).num_days();
-
-        if is_feb29_between_exc_inc(*start, *end) {
-            numerator -= 1;
-        }
-
-        DayCountFraction::new(numerator as f64 / 365.0)
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        let offset = if is_feb29_between_exc_inc(*start, *end) {
+            -1
+        } else {
+            0
+        };
+
+        actual_fraction(start, end, offset, (365, 1))
     }
-    #[cfg(feature = "hifitime")]
-    fn day_count_fraction(&self, start: &Epoch, end: &Epoch) -> DayCountFraction<Self> {
-        let mut numerator = (*end - *start).to_unit(Unit::Day);
 
+    fn day_count<T: DayCountDate>(&self, start: &T, end: &T) -> i64 {
+        let days = actual_days(start, end);
         if is_feb29_between_exc_inc(*start, *end) {
-            numerator -= 1.0;
+            days - 1
+        } else {
+            days
         }
+    }
 
-        DayCountFraction::new(numerator / 365.0)
+    fn days_in_basis<T: DayCountDate>(&self, _start: &T, _end: &T) -> Option<f64> {
+        Some(365.0)
     }
 }
 
@@ -1,9 +1,4 @@
-use crate::{DayCountFraction, DayCounter};
-
-#[cfg(not(feature = "hifitime"))]
-use chrono::NaiveDate;
-#[cfg(feature = "hifitime")]
-use hifitime::Epoch;
+use crate::{DayCountDate, DayCountFraction, DayCounter};
 
 /// 1/1
 ///
@@ -13,13 +8,8 @@ use hifitime::Epoch;
 pub struct OneOne;
 
 impl DayCounter for OneOne {
-    #[cfg(not(feature = "hifitime"))]
-    fn day_count_fraction(&self, _start: &NaiveDate, _end: &NaiveDate) -> DayCountFraction<Self> {
-        DayCountFraction::new(1.0)
-    }
-    #[cfg(feature = "hifitime")]
-    fn day_count_fraction(&self, _start: &Epoch, _end: &Epoch) -> DayCountFraction<Self> {
-        DayCountFraction::new(1.0)
+    fn day_count_fraction<T: DayCountDate>(&self, _start: &T, _end: &T) -> DayCountFraction<Self> {
+        DayCountFraction::from_ratio(1, 1)
     }
 }
 
@@ -0,0 +1,334 @@
+use crate::excel::{
+    YearFrac30360US, YearFrac30E360, YearFracActual360, YearFracActual365, YearFracActualActual,
+};
+use crate::{
+    Actual360, Actual360Inc, Actual364, Actual36525, Actual36525Inc, Actual365A, Actual365Fixed,
+    Actual366, Actual366Inc, ActualActualAFB, ActualActualISDA, ConventionMismatchError,
+    DayCountConvention, OneOne, Thirty360, Thirty360ICMA, Thirty365, ThirtyE360,
+    ThirtyEPlus360ISDA, NL365,
+};
+
+/// Error returned when parsing a day count convention from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseConventionError {
+    /// The input is not a recognised day count convention code.
+    Unknown(String),
+    /// The input is a recognised code, but for a different convention than
+    /// the one being parsed.
+    Mismatch {
+        /// The string that was parsed.
+        input: String,
+        /// The convention the string refers to, and the one that was requested.
+        source: ConventionMismatchError,
+    },
+    /// The input is a recognised code, but for a convention with parameters
+    /// (e.g. a reference period or a holiday calendar), which a string cannot
+    /// carry. Such conventions are built with their constructors instead.
+    RequiresParameters {
+        /// The string that was parsed.
+        input: String,
+        /// The name of the convention the string refers to.
+        convention: &'static str,
+    },
+}
+
+impl std::fmt::Display for ParseConventionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown(input) => write!(f, "unknown day count convention \"{input}\""),
+            Self::Mismatch { input, source } => write!(
+                f,
+                "\"{input}\" refers to the {} day count convention, expected {}",
+                source.found, source.expected
+            ),
+            Self::RequiresParameters { input, convention } => write!(
+                f,
+                "\"{input}\" refers to the {convention} day count convention, which requires parameters"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseConventionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Mismatch { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+type Alias = (&'static [&'static str], fn() -> DayCountConvention);
+
+/// Aliases for each convention, as they appear in trade feeds and term sheets.
+///
+/// Matching ignores case, whitespace, brackets, dots, hyphens and underscores,
+/// and treats "Actual" and "Act" as equivalent.
+const ALIASES: &[Alias] = &[
+    (&["Actual/360", "Act360", "A/360", "A360", "French"], || {
+        Actual360.into()
+    }),
+    (&["Actual/360 (inc)", "Act+1/360"], || Actual360Inc.into()),
+    (&["Actual/364", "Act364", "A/364", "A364"], || {
+        Actual364.into()
+    }),
+    (&["Actual/365 (A)", "Act365A", "A/365A"], || {
+        Actual365A.into()
+    }),
+    (
+        &[
+            "Actual/365 (Fixed)",
+            "Act/365F",
+            "Act/365",
+            "Act365F",
+            "Act365",
+            "A/365 (Fixed)",
+            "A/365F",
+            "A/365",
+            "A365F",
+            "A365",
+            "English",
+        ],
+        || Actual365Fixed.into(),
+    ),
+    (&["Actual/366", "Act366", "A/366", "A366"], || {
+        Actual366.into()
+    }),
+    (&["Actual/366 (inc)", "Act+1/366"], || Actual366Inc.into()),
+    (&["Actual/365.25", "Act365.25", "A/365.25"], || {
+        Actual36525.into()
+    }),
+    (&["Actual/365.25 (inc)", "Act+1/365.25"], || {
+        Actual36525Inc.into()
+    }),
+    (&["Actual/Actual (AFB)", "Act/Act Euro", "A/A AFB"], || {
+        ActualActualAFB.into()
+    }),
+    (
+        &[
+            "Actual/Actual (ISDA)",
+            "Act/Act",
+            "Act/Act Historical",
+            "ActAct",
+            "A/A",
+            "A/A ISDA",
+        ],
+        || ActualActualISDA.into(),
+    ),
+    (
+        &["NL/365", "NL365", "Act/365 NL", "Act/365 No Leap"],
+        || NL365.into(),
+    ),
+    (&["1/1"], || OneOne.into()),
+    (
+        &[
+            "30/360",
+            "30/360 Bond Basis",
+            "30/360 ISDA",
+            "Bond Basis",
+            "30A/360",
+            "360/360",
+        ],
+        || Thirty360.into(),
+    ),
+    (&["30/360 (ICMA)", "30/360 ISMA"], || Thirty360ICMA.into()),
+    (&["30/365"], || Thirty365.into()),
+    (
+        &[
+            "30E/360",
+            "30/360 Eurobond",
+            "Eurobond Basis",
+            "30S/360",
+            "Special German",
+        ],
+        || ThirtyE360.into(),
+    ),
+    (&["30E+/360 (ISDA)", "30E+/360"], || {
+        ThirtyEPlus360ISDA.into()
+    }),
+    (&["YEARFRAC (basis 0)"], || YearFrac30360US.into()),
+    (&["YEARFRAC (basis 1)"], || YearFracActualActual.into()),
+    (&["YEARFRAC (basis 2)"], || YearFracActual360.into()),
+    (&["YEARFRAC (basis 3)"], || YearFracActual365.into()),
+    (&["YEARFRAC (basis 4)"], || YearFrac30E360.into()),
+];
+
+/// Aliases for the conventions with parameters (e.g. a reference period or a
+/// holiday calendar), which a string cannot carry. The first alias of each is
+/// its name.
+const PARAMETERIZED: &[&[&str]] = &[
+    &[
+        "Actual/365 (Canadian Bond)",
+        "Act/365 Canadian",
+        "Act/365 CAD",
+    ],
+    &[
+        "Actual/365L",
+        "Act/365 Leap",
+        "Act365L",
+        "A/365L",
+        "ISMA-Year",
+    ],
+    &[
+        "Actual/Actual (ICMA)",
+        "Act/Act ISMA",
+        "Act/Act Bond",
+        "A/A ICMA",
+        "ISMA-99",
+    ],
+    &["Business/252", "Bus/252", "BD/252", "BU/252"],
+    &["30/360 (German)", "German"],
+    &["30/360 (US)", "30U/360", "30US/360", "30/360 SIA"],
+    &["30E/360 (ISDA)"],
+];
+
+fn normalize(s: &str) -> String {
+    s.chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '(' | ')' | '.' | '-' | '_'))
+        .collect::<String>()
+        .to_uppercase()
+        .replace("ACTUAL", "ACT")
+}
+
+impl std::str::FromStr for DayCountConvention {
+    type Err = ParseConventionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = normalize(s);
+        let matches = |aliases: &[&str]| aliases.iter().any(|alias| normalize(alias) == normalized);
+        if let Some((_, convention)) = ALIASES.iter().find(|(aliases, _)| matches(aliases)) {
+            return Ok(convention());
+        }
+        match PARAMETERIZED.iter().find(|aliases| matches(aliases)) {
+            Some(aliases) => Err(ParseConventionError::RequiresParameters {
+                input: s.to_string(),
+                convention: aliases[0],
+            }),
+            None => Err(ParseConventionError::Unknown(s.to_string())),
+        }
+    }
+}
+
+macro_rules! impl_from_str {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl std::str::FromStr for $ty {
+                type Err = ParseConventionError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    Self::try_from(s.parse::<DayCountConvention>()?).map_err(|source| {
+                        ParseConventionError::Mismatch {
+                            input: s.to_string(),
+                            source,
+                        }
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_from_str!(
+    Actual360,
+    Actual360Inc,
+    Actual364,
+    Actual365A,
+    Actual365Fixed,
+    Actual366,
+    Actual366Inc,
+    Actual36525,
+    Actual36525Inc,
+    ActualActualAFB,
+    ActualActualISDA,
+    NL365,
+    OneOne,
+    Thirty360,
+    Thirty360ICMA,
+    Thirty365,
+    ThirtyE360,
+    ThirtyEPlus360ISDA,
+    YearFrac30360US,
+    YearFracActualActual,
+    YearFracActual360,
+    YearFracActual365,
+    YearFrac30E360,
+);
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::{
+        Actual360, Actual364, Actual365Fixed, Actual366, ActualActualISDA, DayCountConvention,
+        ParseConventionError, Thirty360, NL365,
+    };
+
+    #[test]
+    fn parse_aliases() {
+        assert_eq!("ACT/360".parse(), Ok(Actual360));
+        assert_eq!("A360".parse(), Ok(Actual360));
+        assert_eq!("Act/365F".parse(), Ok(Actual365Fixed));
+        assert_eq!("30/360 BOND BASIS".parse(), Ok(Thirty360));
+        assert_eq!("ACT360".parse(), Ok(Actual360));
+        assert_eq!("ACT364".parse(), Ok(Actual364));
+        assert_eq!("ACT365".parse(), Ok(Actual365Fixed));
+        assert_eq!("ACT365F".parse(), Ok(Actual365Fixed));
+        assert_eq!("A/365 (Fixed)".parse(), Ok(Actual365Fixed));
+        assert_eq!("ACT366".parse(), Ok(Actual366));
+        assert_eq!("ACTACT".parse(), Ok(ActualActualISDA));
+        assert_eq!("NL365".parse(), Ok(NL365));
+        assert_eq!("30/360 ISDA".parse(), Ok(Thirty360));
+
+        let error = "ACT/360".parse::<Thirty360>().unwrap_err();
+        assert!(matches!(error, ParseConventionError::Mismatch { .. }));
+        assert!(error.source().is_some());
+        assert!(matches!(
+            "ACT/999".parse::<DayCountConvention>(),
+            Err(ParseConventionError::Unknown(_))
+        ));
+    }
+
+    #[test]
+    fn parse_requires_parameters() {
+        for aliases in super::PARAMETERIZED {
+            for alias in *aliases {
+                assert_eq!(
+                    alias.parse::<DayCountConvention>(),
+                    Err(ParseConventionError::RequiresParameters {
+                        input: (*alias).to_string(),
+                        convention: aliases[0],
+                    })
+                );
+            }
+        }
+        assert_eq!(
+            "30E/360.ISDA".parse::<DayCountConvention>(),
+            Err(ParseConventionError::RequiresParameters {
+                input: "30E/360.ISDA".to_string(),
+                convention: "30E/360 (ISDA)",
+            })
+        );
+    }
+
+    #[test]
+    fn parse_aliases_are_unique() {
+        let mut aliases = super::ALIASES
+            .iter()
+            .map(|(aliases, _)| *aliases)
+            .chain(super::PARAMETERIZED.iter().copied())
+            .flat_map(|aliases| aliases.iter().map(|alias| super::normalize(alias)))
+            .collect::<Vec<_>>();
+        let count = aliases.len();
+        aliases.sort();
+        aliases.dedup();
+        assert_eq!(aliases.len(), count);
+    }
+
+    #[test]
+    fn parse_display_names() {
+        for (_, convention) in super::ALIASES {
+            let convention = convention();
+            assert_eq!(convention.to_string().parse(), Ok(convention));
+        }
+    }
+}
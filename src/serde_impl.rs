@@ -0,0 +1,442 @@
+use serde::de::{Error, Unexpected};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::excel::{
+    YearFrac30360US, YearFrac30E360, YearFracActual360, YearFracActual365, YearFracActualActual,
+};
+use crate::{
+    Actual360, Actual360Inc, Actual364, Actual36525, Actual36525Inc, Actual365A, Actual365Canadian,
+    Actual365Fixed, Actual365L, Actual366, Actual366Inc, ActualActualAFB, ActualActualICMA,
+    ActualActualISDA, Business252, CivilDate, DayCountConvention, DayCountFraction, DayCounter,
+    Frequency, HolidayCalendar, HolidayList, OneOne, Thirty360, Thirty360German, Thirty360ICMA,
+    Thirty360US, Thirty365, ThirtyE360, ThirtyE360ISDA, ThirtyEPlus360ISDA, NL365,
+};
+
+/// Dates are (de)serialized as `YYYY-MM-DD` strings.
+impl Serialize for CivilDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CivilDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let date = String::deserialize(deserializer)?;
+        parse_date(&date).ok_or_else(|| {
+            D::Error::invalid_value(Unexpected::Str(&date), &"a date formatted as YYYY-MM-DD")
+        })
+    }
+}
+
+fn parse_date(date: &str) -> Option<CivilDate> {
+    let mut parts = date.splitn(3, '-');
+    let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return None;
+    }
+    CivilDate::from_ymd_opt(year.parse().ok()?, month.parse().ok()?, day.parse().ok()?)
+}
+
+impl Serialize for HolidayList {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.holidays.iter())
+    }
+}
+
+/// A missing list (`null`, as [`WeekendsOnly`](crate::WeekendsOnly) is
+/// serialized) has no holidays.
+impl<'de> Deserialize<'de> for HolidayList {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let holidays = Option::<Vec<CivilDate>>::deserialize(deserializer)?;
+        Ok(Self::new(holidays.unwrap_or_default()))
+    }
+}
+
+/// Conventions without parameters are (de)serialized as their name.
+macro_rules! impl_serde_by_name {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let name = String::deserialize(deserializer)?;
+                    let convention = Self::default();
+                    let expected = convention.to_string();
+                    if name == expected {
+                        Ok(convention)
+                    } else {
+                        Err(D::Error::invalid_value(Unexpected::Str(&name), &expected.as_str()))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_by_name!(
+    Actual360,
+    Actual360Inc,
+    Actual364,
+    Actual365A,
+    Actual365Fixed,
+    Actual366,
+    Actual366Inc,
+    Actual36525,
+    Actual36525Inc,
+    ActualActualAFB,
+    ActualActualISDA,
+    NL365,
+    OneOne,
+    Thirty360,
+    Thirty360ICMA,
+    Thirty365,
+    ThirtyE360,
+    ThirtyEPlus360ISDA,
+    YearFrac30360US,
+    YearFracActualActual,
+    YearFracActual360,
+    YearFracActual365,
+    YearFrac30E360,
+);
+
+/// Conventions with parameters are (de)serialized as a map holding their name
+/// under `"convention"`, alongside their parameters. The name is checked when
+/// deserializing, and so are the parameters of conventions built through a
+/// checked constructor (`via new`).
+macro_rules! impl_deserialize_tagged {
+    (@build $ty:ident { $($field:ident),* }) => {
+        Ok($ty { $($field),* })
+    };
+    (@build $ty:ident { $($field:ident),* } $new:ident) => {
+        $ty::$new($($field),*).ok_or_else(|| {
+            Error::custom(concat!("invalid parameters for ", stringify!($ty)))
+        })
+    };
+    ($($ty:ident { $($field:ident: $field_ty:ty),* $(,)? } => $name:literal $(via $new:ident)?),* $(,)?) => {
+        $(
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    #[derive(Deserialize)]
+                    #[serde(tag = "convention")]
+                    enum Tagged {
+                        #[serde(rename = $name)]
+                        Convention { $($field: $field_ty),* },
+                    }
+
+                    let Tagged::Convention { $($field),* } = Tagged::deserialize(deserializer)?;
+                    impl_deserialize_tagged!(@build $ty { $($field),* } $($new)?)
+                }
+            }
+        )*
+    };
+}
+
+impl_deserialize_tagged!(
+    Actual365Canadian {
+        reference_start: CivilDate,
+        reference_end: CivilDate,
+        frequency: Frequency,
+    } => "Actual/365 (Canadian Bond)" via new,
+    Actual365L { frequency: Frequency } => "Actual/365L",
+    ActualActualICMA {
+        reference_start: CivilDate,
+        reference_end: CivilDate,
+        frequency: Frequency,
+    } => "Actual/Actual (ICMA)" via new,
+    Thirty360German { maturity_date: CivilDate } => "30/360 (German)",
+    Thirty360US { end_of_month: bool } => "30/360 (US)",
+    ThirtyE360ISDA { termination_date: CivilDate } => "30E/360 (ISDA)",
+);
+
+impl<'de, C> Deserialize<'de> for Business252<C>
+where
+    C: HolidayCalendar + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(tag = "convention")]
+        enum Tagged<C> {
+            #[serde(rename = "Business/252")]
+            Convention { calendar: C },
+        }
+
+        let Tagged::Convention { calendar } = Tagged::deserialize(deserializer)?;
+        Ok(Self { calendar })
+    }
+}
+
+/// Each representation checks the name of the convention, so the first one
+/// that deserializes is the right one.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ConventionRepr {
+    Name(String),
+    Actual365Canadian(Actual365Canadian),
+    Actual365L(Actual365L),
+    ActualActualICMA(ActualActualICMA),
+    Business252(Business252<HolidayList>),
+    Thirty360German(Thirty360German),
+    Thirty360US(Thirty360US),
+    ThirtyE360ISDA(ThirtyE360ISDA),
+}
+
+impl<'de> Deserialize<'de> for DayCountConvention {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match ConventionRepr::deserialize(deserializer)? {
+            ConventionRepr::Name(name) => return name.parse().map_err(D::Error::custom),
+            ConventionRepr::Actual365Canadian(dc) => dc.into(),
+            ConventionRepr::Actual365L(dc) => dc.into(),
+            ConventionRepr::ActualActualICMA(dc) => dc.into(),
+            ConventionRepr::Business252(dc) => dc.into(),
+            ConventionRepr::Thirty360German(dc) => dc.into(),
+            ConventionRepr::Thirty360US(dc) => dc.into(),
+            ConventionRepr::ThirtyE360ISDA(dc) => dc.into(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "DayCountFraction")]
+struct DayCountFractionRepr {
+    convention: String,
+    fraction: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    numerator: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    denominator: Option<i64>,
+}
+
+impl<D> Serialize for DayCountFraction<D>
+where
+    D: DayCounter,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        DayCountFractionRepr {
+            convention: D::default().to_string(),
+            fraction: self.get_fraction(),
+            numerator: self.numerator(),
+            denominator: self.denominator(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, D> Deserialize<'de> for DayCountFraction<D>
+where
+    D: DayCounter,
+{
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let repr = DayCountFractionRepr::deserialize(deserializer)?;
+        let expected = D::default().to_string();
+        if repr.convention != expected {
+            return Err(De::Error::invalid_value(
+                Unexpected::Str(&repr.convention),
+                &expected.as_str(),
+            ));
+        }
+        match (repr.numerator, repr.denominator) {
+            (Some(_), Some(0)) => Err(De::Error::invalid_value(
+                Unexpected::Signed(0),
+                &"a non-zero denominator",
+            )),
+            (Some(numerator), Some(denominator)) => {
+                Ok(Self::with_value(repr.fraction, numerator, denominator))
+            }
+            _ => Ok(Self::new(repr.fraction)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::excel::{
+        YearFrac30360US, YearFrac30E360, YearFracActual360, YearFracActual365, YearFracActualActual,
+    };
+    use crate::{
+        Actual360, Actual360Inc, Actual364, Actual36525, Actual36525Inc, Actual365A,
+        Actual365Canadian, Actual365Fixed, Actual365L, Actual366, Actual366Inc, ActualActualAFB,
+        ActualActualICMA, ActualActualISDA, Business252, CivilDate, DayCountConvention,
+        DayCountDate, DayCountFraction, DayCounter, Frequency, HolidayList, OneOne, Thirty360,
+        Thirty360German, Thirty360ICMA, Thirty360US, Thirty365, ThirtyE360, ThirtyE360ISDA,
+        ThirtyEPlus360ISDA, WeekendsOnly, NL365,
+    };
+
+    #[test]
+    fn serialize_by_name() {
+        assert_eq!(serde_json::to_string(&Actual360).unwrap(), "\"Actual/360\"");
+        assert_eq!(
+            serde_json::from_str::<Actual360>("\"Actual/360\"").unwrap(),
+            Actual360
+        );
+        assert!(serde_json::from_str::<Thirty360>("\"Actual/360\"").is_err());
+
+        let convention: DayCountConvention = serde_json::from_str("\"ACT/360\"").unwrap();
+        assert_eq!(convention, DayCountConvention::from(Actual360));
+
+        let convention = DayCountConvention::from(YearFracActualActual);
+        let json = serde_json::to_string(&convention).unwrap();
+        assert_eq!(json, "\"YEARFRAC (basis 1)\"");
+        assert_eq!(
+            serde_json::from_str::<DayCountConvention>(&json).unwrap(),
+            convention
+        );
+    }
+
+    #[test]
+    fn serialize_fraction() {
+        let yf = DayCountFraction::<Actual360>::new(0.5);
+        let json = serde_json::to_string(&yf).unwrap();
+        assert_eq!(json, r#"{"convention":"Actual/360","fraction":0.5}"#);
+        assert!(serde_json::from_str::<DayCountFraction<Actual360>>(&json).unwrap() == yf);
+        assert!(serde_json::from_str::<DayCountFraction<Thirty360>>(&json).is_err());
+
+        let yf = DayCountFraction::<Actual360>::from_ratio(92, 360);
+        let json = serde_json::to_string(&yf).unwrap();
+        assert_eq!(
+            json,
+            r#"{"convention":"Actual/360","fraction":0.25555555555555554,"numerator":23,"denominator":90}"#
+        );
+        let de = serde_json::from_str::<DayCountFraction<Actual360>>(&json).unwrap();
+        assert_eq!((de.numerator(), de.denominator()), (Some(23), Some(90)));
+
+        // The value is kept as stored, even when it is not the quotient of the ratio.
+        let yf = YearFracActualActual.day_count_fraction(
+            &CivilDate::from_ymd(2010, 1, 1),
+            &CivilDate::from_ymd(2012, 7, 1),
+        );
+        assert_ne!(yf.get_fraction(), 342.0 / 137.0);
+        let json = serde_json::to_string(&yf).unwrap();
+        let de = serde_json::from_str::<DayCountFraction<YearFracActualActual>>(&json).unwrap();
+        assert_eq!(de, yf);
+        assert_eq!(de.get_fraction(), yf.get_fraction());
+        assert_eq!(
+            (de.numerator(), de.denominator()),
+            (yf.numerator(), yf.denominator())
+        );
+    }
+
+    #[test]
+    fn serialize_every_convention() {
+        let day1 = CivilDate::from_ymd(2024, 1, 31);
+        let day2 = CivilDate::from_ymd(2024, 7, 31);
+        let conventions: [DayCountConvention; 30] = [
+            Actual360.into(),
+            Actual360Inc.into(),
+            Actual364.into(),
+            Actual365A.into(),
+            Actual365Canadian::new(day1, day2, Frequency::SemiAnnual)
+                .unwrap()
+                .into(),
+            Actual365Fixed.into(),
+            Actual365L::new(Frequency::SemiAnnual).into(),
+            Actual366.into(),
+            Actual366Inc.into(),
+            Actual36525.into(),
+            Actual36525Inc.into(),
+            ActualActualAFB.into(),
+            ActualActualICMA::new(day1, day2, Frequency::SemiAnnual)
+                .unwrap()
+                .into(),
+            ActualActualISDA.into(),
+            Business252::new(HolidayList::new(vec![day1])).into(),
+            NL365.into(),
+            OneOne.into(),
+            Thirty360.into(),
+            Thirty360German::new(day2).into(),
+            Thirty360ICMA.into(),
+            Thirty360US::new(true).into(),
+            Thirty365.into(),
+            ThirtyE360.into(),
+            ThirtyE360ISDA::new(day2).into(),
+            ThirtyEPlus360ISDA.into(),
+            YearFrac30360US.into(),
+            YearFracActualActual.into(),
+            YearFracActual360.into(),
+            YearFracActual365.into(),
+            YearFrac30E360.into(),
+        ];
+        for convention in conventions {
+            let json = serde_json::to_string(&convention).unwrap();
+            assert_eq!(
+                serde_json::from_str::<DayCountConvention>(&json).unwrap(),
+                convention,
+                "{json}"
+            );
+        }
+
+        // A name alone cannot carry the parameters of a convention.
+        for name in [
+            "\"Actual/Actual (ICMA)\"",
+            "\"Business/252\"",
+            "\"30/360 (US)\"",
+        ] {
+            assert!(serde_json::from_str::<DayCountConvention>(name).is_err());
+        }
+    }
+
+    fn serialize_parameters<T: DayCountDate>() {
+        let dc = ThirtyE360ISDA::new(T::from_ymd(2030, 2, 28));
+        let json = serde_json::to_string(&dc).unwrap();
+        assert_eq!(
+            json,
+            r#"{"convention":"30E/360 (ISDA)","termination_date":"2030-02-28"}"#
+        );
+        assert_eq!(serde_json::from_str::<ThirtyE360ISDA>(&json).unwrap(), dc);
+
+        let convention: DayCountConvention = serde_json::from_str(&json).unwrap();
+        assert_eq!(convention, DayCountConvention::from(dc));
+        assert_eq!(serde_json::to_string(&convention).unwrap(), json);
+
+        let wrong = r#"{"convention":"WRONG","termination_date":"2030-02-28"}"#;
+        assert!(serde_json::from_str::<ThirtyE360ISDA>(wrong).is_err());
+        assert!(serde_json::from_str::<DayCountConvention>(wrong).is_err());
+        let untagged = r#"{"termination_date":"2030-02-28"}"#;
+        assert!(serde_json::from_str::<ThirtyE360ISDA>(untagged).is_err());
+        let other = r#"{"convention":"Actual/360","frequency":"Annual"}"#;
+        assert!(serde_json::from_str::<Actual365L>(other).is_err());
+        let reversed = r#"{"convention":"Actual/Actual (ICMA)","reference_start":"2024-07-01","reference_end":"2024-01-01","frequency":"SemiAnnual"}"#;
+        assert!(serde_json::from_str::<ActualActualICMA>(reversed).is_err());
+
+        let day1 = T::from_ymd(2024, 1, 1);
+        let day2 = T::from_ymd(2024, 7, 1);
+        assert_eq!(
+            convention.day_count_fraction(&day1, &day2),
+            dc.day_count_fraction(&day1, &day2).get_fraction()
+        );
+    }
+
+    test_date_backends!(serialize_parameters);
+
+    #[test]
+    fn serialize_holidays() {
+        let json = r#"{"convention":"Business/252","calendar":["2024-01-01","2024-12-25"]}"#;
+        let dc: Business252<HolidayList> = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            *dc.calendar.holidays,
+            [
+                CivilDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                CivilDate::from_ymd_opt(2024, 12, 25).unwrap()
+            ]
+        );
+        assert_eq!(serde_json::to_string(&dc).unwrap(), json);
+        assert!(serde_json::from_str::<CivilDate>("\"2023-02-29\"").is_err());
+
+        // The weekend-only calendar is read as an empty list of holidays.
+        let json = serde_json::to_string(&Business252::<WeekendsOnly>::default()).unwrap();
+        assert_eq!(json, r#"{"convention":"Business/252","calendar":null}"#);
+        let weekends_only = Business252::new(HolidayList::default());
+        assert_eq!(
+            serde_json::from_str::<Business252<HolidayList>>(&json).unwrap(),
+            weekends_only
+        );
+        assert_eq!(
+            serde_json::from_str::<DayCountConvention>(&json).unwrap(),
+            DayCountConvention::from(weekends_only)
+        );
+    }
+}
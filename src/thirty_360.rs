@@ -1,9 +1,53 @@
-use crate::{get_last_day_of_month, is_last_day_of_feb, DayCountFraction, DayCounter};
+use crate::{
+    date_from_days, estimate_days, estimate_thirty_days, is_last_day_of_feb, CivilDate,
+    DayCountDate, DayCountFraction, DayCounter,
+};
 
-#[cfg(not(feature = "hifitime"))]
-use chrono::{Datelike, NaiveDate};
-#[cfg(feature = "hifitime")]
-use hifitime::Epoch;
+/// The years, months and days of the two dates of a period, after the
+/// adjustments of a 30/360 convention.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct AdjustedDates {
+    /// The year of $d_1$, $Y_1$.
+    pub y1: i32,
+    /// The month of $d_1$, $M_1$.
+    pub m1: i32,
+    /// The adjusted day of $d_1$, $D_1$.
+    pub d1: i32,
+    /// The year of $d_2$, $Y_2$.
+    pub y2: i32,
+    /// The adjusted month of $d_2$, $M_2$.
+    pub m2: i32,
+    /// The adjusted day of $d_2$, $D_2$.
+    pub d2: i32,
+}
+
+impl AdjustedDates {
+    fn new<T: DayCountDate>(start: &T, end: &T) -> Self {
+        Self {
+            y1: start.year(),
+            m1: start.month() as i32,
+            d1: start.day() as i32,
+            y2: end.year(),
+            m2: end.month() as i32,
+            d2: end.day() as i32,
+        }
+    }
+
+    /// The number of days between the adjusted dates,
+    /// $360 (Y_2 - Y_1) + 30 (M_2 - M_1) + (D_2 - D_1)$.
+    #[must_use]
+    pub const fn days(&self) -> i32 {
+        360 * (self.y2 - self.y1) + 30 * (self.m2 - self.m1) + (self.d2 - self.d1)
+    }
+}
+
+/// The [`ThirtyDayCounter`] trait represents the 30/360 family of day
+/// counters, which count days between dates whose day of the month has been
+/// adjusted (e.g. from 31 to 30).
+pub trait ThirtyDayCounter: DayCounter {
+    /// Given two dates, calculate their adjusted years, months and days.
+    fn adjusted_dates<T: DayCountDate>(&self, start: &T, end: &T) -> AdjustedDates;
+}
 
 /// 30/360
 ///
@@ -23,53 +67,327 @@ use hifitime::Epoch;
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
 pub struct Thirty360;
 
+impl ThirtyDayCounter for Thirty360 {
+    fn adjusted_dates<T: DayCountDate>(&self, start: &T, end: &T) -> AdjustedDates {
+        let mut dates = AdjustedDates::new(start, end);
+
+        if dates.d1 == 31 {
+            dates.d1 = 30;
+        }
+        if dates.d2 == 31 && dates.d1 >= 30 {
+            dates.d2 = 30;
+        }
+
+        dates
+    }
+}
+
 impl DayCounter for Thirty360 {
-    #[cfg(not(feature = "hifitime"))]
-    fn day_count_fraction(&self, start: &NaiveDate, end: &NaiveDate) -> DayCountFraction<Self> {
-        let y1 = start.year();
-        let m1 = start.month() as i32;
-        let mut d1 = start.day() as i32;
-
-        let y2 = end.year();
-        let m2 = end.month() as i32;
-        let mut d2 = end.day() as i32;
-
-        if d1 == 31 {
-            d1 = 30;
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        DayCountFraction::from_ratio(i64::from(self.adjusted_dates(start, end).days()), 360)
+    }
+
+    fn day_count<T: DayCountDate>(&self, start: &T, end: &T) -> i64 {
+        i64::from(self.adjusted_dates(start, end).days())
+    }
+
+    fn days_in_basis<T: DayCountDate>(&self, _start: &T, _end: &T) -> Option<f64> {
+        Some(360.0)
+    }
+
+    fn date_from_fraction<T: DayCountDate>(&self, start: &T, fraction: f64) -> Option<T> {
+        let days = estimate_days(fraction, 360.0);
+        date_from_days(self, start, fraction, estimate_thirty_days(start, days), 0)
+    }
+}
+
+impl std::fmt::Display for Thirty360 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "30/360")
+    }
+}
+
+/// 30/360 (US)
+///
+/// Let $Y_1$ be the year of $d_1$, $M_1$ be the month of $d_1$,
+/// and $D_1$ be the day of $d_1$. Similarly define these for $d_2$.
+///
+/// Apply the following rules, in order:
+/// 1. If the instrument follows the end-of-month convention and both $d_1$
+///    and $d_2$ are the last day of February, then change $D_2$ to 30.
+/// 2. If the instrument follows the end-of-month convention and $d_1$ is the
+///    last day of February, then change $D_1$ to 30.
+/// 3. If $D_2$ is 31 and $D_1$ is either 30 or 31, then change $D_2$ to 30.
+/// 4. If $D_1$ is 31, then change $D_1$ to 30.
+///
+/// Now plug into the following to get the day count fraction.
+///
+/// $$
+/// \frac{360 (Y_2 - Y_1) + 30 (M_2 - M_1) + (D_2 - D_1)}{360}
+/// $$
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(tag = "convention", rename = "30/360 (US)")
+)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Thirty360US {
+    /// Whether the instrument follows the end-of-month convention, i.e. its
+    /// payment dates are always the last day of the month.
+    pub end_of_month: bool,
+}
+
+impl Thirty360US {
+    /// Create a new [`Thirty360US`] with a given end-of-month convention.
+    #[must_use]
+    pub const fn new(end_of_month: bool) -> Self {
+        Self { end_of_month }
+    }
+}
+
+impl ThirtyDayCounter for Thirty360US {
+    fn adjusted_dates<T: DayCountDate>(&self, start: &T, end: &T) -> AdjustedDates {
+        let mut dates = AdjustedDates::new(start, end);
+
+        if self.end_of_month && is_last_day_of_feb(*start) {
+            if is_last_day_of_feb(*end) {
+                dates.d2 = 30;
+            }
+            dates.d1 = 30;
+        }
+        if dates.d2 == 31 && dates.d1 >= 30 {
+            dates.d2 = 30;
         }
-        if d2 == 31 && d1 >= 30 {
-            d2 = 30;
+        if dates.d1 == 31 {
+            dates.d1 = 30;
         }
 
-        let numerator = 360 * (y2 - y1) + 30 * (m2 - m1) + (d2 - d1);
-        DayCountFraction::new(f64::from(numerator) / 360.0)
+        dates
     }
-    #[cfg(feature = "hifitime")]
-    fn day_count_fraction(&self, start: &Epoch, end: &Epoch) -> DayCountFraction<Self> {
-        let (y1, m1, d1, _, _, _, _) = start.to_gregorian_utc();
-        let (y2, m2, d2, _, _, _, _) = end.to_gregorian_utc();
+}
 
-        let m1 = m1 as i32;
-        let mut d1 = d1 as i32;
+impl DayCounter for Thirty360US {
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        DayCountFraction::from_ratio(i64::from(self.adjusted_dates(start, end).days()), 360)
+    }
 
-        let m2 = m2 as i32;
-        let mut d2 = d2 as i32;
+    fn day_count<T: DayCountDate>(&self, start: &T, end: &T) -> i64 {
+        i64::from(self.adjusted_dates(start, end).days())
+    }
 
-        if d1 == 31 {
-            d1 = 30;
+    fn days_in_basis<T: DayCountDate>(&self, _start: &T, _end: &T) -> Option<f64> {
+        Some(360.0)
+    }
+
+    fn date_from_fraction<T: DayCountDate>(&self, start: &T, fraction: f64) -> Option<T> {
+        let days = estimate_days(fraction, 360.0);
+        date_from_days(self, start, fraction, estimate_thirty_days(start, days), 0)
+    }
+}
+
+impl std::fmt::Display for Thirty360US {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "30/360 (US)")
+    }
+}
+
+/// 30/360 (ICMA)
+///
+/// Let $Y_1$ be the year of $d_1$, $M_1$ be the month of $d_1$,
+/// and $D_1$ be the day of $d_1$. Similarly define these for $d_2$.
+///
+/// Apply the following two rules:
+/// 1. If $D_1$ is 31, then change $D_1$ to 30.
+/// 2. If $D_2$ is 31, then change $D_2$ to 30.
+///
+/// Now plug into the following to get the day count fraction.
+///
+/// $$
+/// \frac{360 (Y_2 - Y_1) + 30 (M_2 - M_1) + (D_2 - D_1)}{360}
+/// $$
+///
+/// This is the Eurobond basis of the ICMA rules, and computes the same
+/// fraction as [30E/360](ThirtyE360).
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Thirty360ICMA;
+
+impl ThirtyDayCounter for Thirty360ICMA {
+    fn adjusted_dates<T: DayCountDate>(&self, start: &T, end: &T) -> AdjustedDates {
+        let mut dates = AdjustedDates::new(start, end);
+
+        if dates.d1 == 31 {
+            dates.d1 = 30;
         }
-        if d2 == 31 && d1 >= 30 {
-            d2 = 30;
+        if dates.d2 == 31 {
+            dates.d2 = 30;
         }
 
-        let numerator = (360 * (y2 - y1)) + (30 * (m2 - m1)) + (d2 - d1);
-        DayCountFraction::new(f64::from(numerator) / 360.0)
+        dates
     }
 }
 
-impl std::fmt::Display for Thirty360 {
+impl DayCounter for Thirty360ICMA {
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        DayCountFraction::from_ratio(i64::from(self.adjusted_dates(start, end).days()), 360)
+    }
+
+    fn day_count<T: DayCountDate>(&self, start: &T, end: &T) -> i64 {
+        i64::from(self.adjusted_dates(start, end).days())
+    }
+
+    fn days_in_basis<T: DayCountDate>(&self, _start: &T, _end: &T) -> Option<f64> {
+        Some(360.0)
+    }
+
+    fn date_from_fraction<T: DayCountDate>(&self, start: &T, fraction: f64) -> Option<T> {
+        let days = estimate_days(fraction, 360.0);
+        date_from_days(self, start, fraction, estimate_thirty_days(start, days), 0)
+    }
+}
+
+impl std::fmt::Display for Thirty360ICMA {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "30/360")
+        write!(f, "30/360 (ICMA)")
+    }
+}
+
+/// 30/360 (German)
+///
+/// Let $Y_1$ be the year of $d_1$, $M_1$ be the month of $d_1$,
+/// and $D_1$ be the day of $d_1$. Similarly define these for $d_2$.
+///
+/// Apply the following two rules:
+/// 1. If $D_1$ is 31 or $d_1$ is the last day of February, then change $D_1$
+///    to 30.
+/// 2. If $D_2$ is 31 or $d_2$ is the last day of February but not the
+///    **maturity date**, then change $D_2$ to 30.
+///
+/// Now plug into the following to get the day count fraction.
+///
+/// $$
+/// \frac{360 (Y_2 - Y_1) + 30 (M_2 - M_1) + (D_2 - D_1)}{360}
+/// $$
+///
+/// This computes the same fraction as [30E/360 (ISDA)](ThirtyE360ISDA), with
+/// the maturity date as the termination date.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(tag = "convention", rename = "30/360 (German)")
+)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Thirty360German {
+    /// The maturity date of the instrument. The last day of February is not
+    /// adjusted when it is the maturity date.
+    pub maturity_date: CivilDate,
+}
+
+impl Thirty360German {
+    /// Create a new [`Thirty360German`] with a given maturity date.
+    #[must_use]
+    pub fn new<T: DayCountDate>(maturity_date: T) -> Self {
+        Self {
+            maturity_date: maturity_date.to_civil(),
+        }
+    }
+}
+
+impl ThirtyDayCounter for Thirty360German {
+    fn adjusted_dates<T: DayCountDate>(&self, start: &T, end: &T) -> AdjustedDates {
+        let mut dates = AdjustedDates::new(start, end);
+
+        if dates.d1 == 31 || is_last_day_of_feb(*start) {
+            dates.d1 = 30;
+        }
+        if dates.d2 == 31 || (is_last_day_of_feb(*end) && self.maturity_date != end.to_civil()) {
+            dates.d2 = 30;
+        }
+
+        dates
+    }
+}
+
+impl DayCounter for Thirty360German {
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        DayCountFraction::from_ratio(i64::from(self.adjusted_dates(start, end).days()), 360)
+    }
+
+    fn day_count<T: DayCountDate>(&self, start: &T, end: &T) -> i64 {
+        i64::from(self.adjusted_dates(start, end).days())
+    }
+
+    fn days_in_basis<T: DayCountDate>(&self, _start: &T, _end: &T) -> Option<f64> {
+        Some(360.0)
+    }
+
+    fn date_from_fraction<T: DayCountDate>(&self, start: &T, fraction: f64) -> Option<T> {
+        let days = estimate_days(fraction, 360.0);
+        date_from_days(self, start, fraction, estimate_thirty_days(start, days), 0)
+    }
+}
+
+impl std::fmt::Display for Thirty360German {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "30/360 (German)")
+    }
+}
+
+/// 30/365
+///
+/// Let $Y_1$ be the year of $d_1$, $M_1$ be the month of $d_1$,
+/// and $D_1$ be the day of $d_1$. Similarly define these for $d_2$.
+///
+/// Apply the following two rules:
+/// 1. If $D_1$ is 31, then change $D_1$ to 30.
+/// 2. If $D_2$ is 31 and $D_1$ is either 30 or 31, then change $D_2$ to 30.
+///
+/// Now plug into the following to get the day count fraction.
+///
+/// $$
+/// \frac{360 (Y_2 - Y_1) + 30 (M_2 - M_1) + (D_2 - D_1)}{365}
+/// $$
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Thirty365;
+
+impl ThirtyDayCounter for Thirty365 {
+    fn adjusted_dates<T: DayCountDate>(&self, start: &T, end: &T) -> AdjustedDates {
+        let mut dates = AdjustedDates::new(start, end);
+
+        if dates.d1 == 31 {
+            dates.d1 = 30;
+        }
+        if dates.d2 == 31 && dates.d1 >= 30 {
+            dates.d2 = 30;
+        }
+
+        dates
+    }
+}
+
+impl DayCounter for Thirty365 {
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        DayCountFraction::from_ratio(i64::from(self.adjusted_dates(start, end).days()), 365)
+    }
+
+    fn day_count<T: DayCountDate>(&self, start: &T, end: &T) -> i64 {
+        i64::from(self.adjusted_dates(start, end).days())
+    }
+
+    fn days_in_basis<T: DayCountDate>(&self, _start: &T, _end: &T) -> Option<f64> {
+        Some(365.0)
+    }
+
+    fn date_from_fraction<T: DayCountDate>(&self, start: &T, fraction: f64) -> Option<T> {
+        let days = estimate_days(fraction, 365.0);
+        date_from_days(self, start, fraction, estimate_thirty_days(start, days), 0)
+    }
+}
+
+impl std::fmt::Display for Thirty365 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "30/365")
     }
 }
 
@@ -91,47 +409,37 @@ impl std::fmt::Display for Thirty360 {
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
 pub struct ThirtyE360;
 
-impl DayCounter for ThirtyE360 {
-    #[cfg(not(feature = "hifitime"))]
-    fn day_count_fraction(&self, start: &NaiveDate, end: &NaiveDate) -> DayCountFraction<Self> {
-        let y1 = start.year();
-        let m1 = start.month() as i32;
-        let mut d1 = start.day() as i32;
-
-        let y2 = end.year();
-        let m2 = end.month() as i32;
-        let mut d2 = end.day() as i32;
-
-        if d1 == 31 {
-            d1 = 30;
+impl ThirtyDayCounter for ThirtyE360 {
+    fn adjusted_dates<T: DayCountDate>(&self, start: &T, end: &T) -> AdjustedDates {
+        let mut dates = AdjustedDates::new(start, end);
+
+        if dates.d1 == 31 {
+            dates.d1 = 30;
         }
-        if d2 == 31 {
-            d2 = 30;
+        if dates.d2 == 31 {
+            dates.d2 = 30;
         }
 
-        let numerator = 360 * (y2 - y1) + 30 * (m2 - m1) + (d2 - d1);
-        DayCountFraction::new(f64::from(numerator) / 360.0)
+        dates
     }
-    #[cfg(feature = "hifitime")]
-    fn day_count_fraction(&self, start: &Epoch, end: &Epoch) -> DayCountFraction<Self> {
-        let (y1, m1, d1, _, _, _, _) = start.to_gregorian_utc();
-        let (y2, m2, d2, _, _, _, _) = end.to_gregorian_utc();
+}
 
-        let m1 = m1 as i32;
-        let mut d1 = d1 as i32;
+impl DayCounter for ThirtyE360 {
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        DayCountFraction::from_ratio(i64::from(self.adjusted_dates(start, end).days()), 360)
+    }
 
-        let m2 = m2 as i32;
-        let mut d2 = d2 as i32;
+    fn day_count<T: DayCountDate>(&self, start: &T, end: &T) -> i64 {
+        i64::from(self.adjusted_dates(start, end).days())
+    }
 
-        if d1 == 31 {
-            d1 = 30;
-        }
-        if d2 == 31 {
-            d2 = 30;
-        }
+    fn days_in_basis<T: DayCountDate>(&self, _start: &T, _end: &T) -> Option<f64> {
+        Some(360.0)
+    }
 
-        let numerator = (360 * (y2 - y1)) + (30 * (m2 - m1)) + (d2 - d1);
-        DayCountFraction::new(f64::from(numerator) / 360.0)
+    fn date_from_fraction<T: DayCountDate>(&self, start: &T, fraction: f64) -> Option<T> {
+        let days = estimate_days(fraction, 360.0);
+        date_from_days(self, start, fraction, estimate_thirty_days(start, days), 0)
     }
 }
 
@@ -156,79 +464,61 @@ impl std::fmt::Display for ThirtyE360 {
 /// $$
 /// \frac{360 (Y_2 - Y_1) + 30 (M_2 - M_1) + (D_2 - D_1)}{360}
 /// $$
-
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(tag = "convention", rename = "30E/360 (ISDA)")
+)]
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
 pub struct ThirtyE360ISDA {
     /// The termination date is the last date on which **new** obligations arise under the swap contract.
     ///
     /// Source: [2006 ISDA Definitions Chapter 3 Section 3](https://jollycontrarian.com/index.php?title=Termination_Date_-_ISDA_Definition)
-    #[cfg(not(feature = "hifitime"))]
-    pub termination_date: NaiveDate,
-    /// The termination date is the last date on which **new** obligations arise under the swap contract.
-    ///
-    /// Source: [2006 ISDA Definitions Chapter 3 Section 3](https://jollycontrarian.com/index.php?title=Termination_Date_-_ISDA_Definition)
-    #[cfg(feature = "hifitime")]
-    pub termination_date: Epoch,
+    pub termination_date: CivilDate,
 }
 
 impl ThirtyE360ISDA {
-    #[cfg(not(feature = "hifitime"))]
-    /// Create a new [`ThirtyE360ISDA`] with a given termination date.
-    #[must_use]
-    pub const fn new(termination_date: NaiveDate) -> Self {
-        Self { termination_date }
-    }
-    #[cfg(feature = "hifitime")]
     /// Create a new [`ThirtyE360ISDA`] with a given termination date.
     #[must_use]
-    pub const fn new(termination_date: Epoch) -> Self {
-        Self { termination_date }
+    pub fn new<T: DayCountDate>(termination_date: T) -> Self {
+        Self {
+            termination_date: termination_date.to_civil(),
+        }
     }
 }
 
-impl DayCounter for ThirtyE360ISDA {
-    #[cfg(not(feature = "hifitime"))]
-    fn day_count_fraction(&self, start: &NaiveDate, end: &NaiveDate) -> DayCountFraction<Self> {
-        let y1 = start.year();
-        let m1 = start.month();
-        let mut d1 = start.day() as i32;
-
-        let y2 = end.year();
-        let m2 = end.month();
-        let mut d2 = end.day() as i32;
-
-        if get_last_day_of_month(y1, m1) == d1 {
-            d1 = 30;
+impl ThirtyDayCounter for ThirtyE360ISDA {
+    fn adjusted_dates<T: DayCountDate>(&self, start: &T, end: &T) -> AdjustedDates {
+        let mut dates = AdjustedDates::new(start, end);
+
+        if start.is_last_day_of_month() {
+            dates.d1 = 30;
         }
 
-        if is_last_day_of_feb(*end) && (self.termination_date != *end || d2 == 31) {
-            d2 = 30;
+        if is_last_day_of_feb(*end) && (self.termination_date != end.to_civil() || dates.d2 == 31) {
+            dates.d2 = 30;
         }
 
-        let numerator = 360 * (y2 - y1) + 30 * ((m2 - m1) as i32) + (d2 - d1);
-        DayCountFraction::new(f64::from(numerator) / 360.0)
+        dates
     }
-    #[cfg(feature = "hifitime")]
-    fn day_count_fraction(&self, start: &Epoch, end: &Epoch) -> DayCountFraction<Self> {
-        let (y1, m1, d1, _, _, _, _) = start.to_gregorian_utc();
-        let (y2, m2, d2, _, _, _, _) = end.to_gregorian_utc();
-
-        let m1 = m1 as i32;
-        let mut d1 = d1 as i32;
+}
 
-        let m2 = m2 as i32;
-        let mut d2 = d2 as i32;
+impl DayCounter for ThirtyE360ISDA {
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        DayCountFraction::from_ratio(i64::from(self.adjusted_dates(start, end).days()), 360)
+    }
 
-        if get_last_day_of_month(y1, m1.try_into().unwrap()) == d1 {
-            d1 = 30;
-        }
+    fn day_count<T: DayCountDate>(&self, start: &T, end: &T) -> i64 {
+        i64::from(self.adjusted_dates(start, end).days())
+    }
 
-        if is_last_day_of_feb(*end) && (self.termination_date != *end || d2 == 31) {
-            d2 = 30;
-        }
+    fn days_in_basis<T: DayCountDate>(&self, _start: &T, _end: &T) -> Option<f64> {
+        Some(360.0)
+    }
 
-        let numerator = (360 * (y2 - y1)) + (30 * (m2 - m1)) + (d2 - d1);
-        DayCountFraction::new(f64::from(numerator) / 360.0)
+    fn date_from_fraction<T: DayCountDate>(&self, start: &T, fraction: f64) -> Option<T> {
+        let days = estimate_days(fraction, 360.0);
+        date_from_days(self, start, fraction, estimate_thirty_days(start, days), 0)
     }
 }
 
@@ -256,49 +546,38 @@ impl std::fmt::Display for ThirtyE360ISDA {
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
 pub struct ThirtyEPlus360ISDA;
 
-impl DayCounter for ThirtyEPlus360ISDA {
-    #[cfg(not(feature = "hifitime"))]
-    fn day_count_fraction(&self, start: &NaiveDate, end: &NaiveDate) -> DayCountFraction<Self> {
-        let y1 = start.year();
-        let m1 = start.month() as i32;
-        let mut d1 = start.day() as i32;
-
-        let y2 = end.year();
-        let mut m2 = end.month() as i32;
-        let mut d2 = end.day() as i32;
-
-        if d1 == 31 {
-            d1 = 30;
+impl ThirtyDayCounter for ThirtyEPlus360ISDA {
+    fn adjusted_dates<T: DayCountDate>(&self, start: &T, end: &T) -> AdjustedDates {
+        let mut dates = AdjustedDates::new(start, end);
+
+        if dates.d1 == 31 {
+            dates.d1 = 30;
         }
-        if d2 == 31 {
-            d2 = 1;
-            m2 += 1;
+        if dates.d2 == 31 {
+            dates.d2 = 1;
+            dates.m2 += 1;
         }
 
-        let numerator = 360 * (y2 - y1) + 30 * (m2 - m1) + (d2 - d1);
-        DayCountFraction::new(f64::from(numerator) / 360.0)
+        dates
     }
-    #[cfg(feature = "hifitime")]
-    fn day_count_fraction(&self, start: &Epoch, end: &Epoch) -> DayCountFraction<Self> {
-        let (y1, m1, d1, _, _, _, _) = start.to_gregorian_utc();
-        let (y2, m2, d2, _, _, _, _) = end.to_gregorian_utc();
+}
 
-        let m1 = m1 as i32;
-        let mut d1 = d1 as i32;
+impl DayCounter for ThirtyEPlus360ISDA {
+    fn day_count_fraction<T: DayCountDate>(&self, start: &T, end: &T) -> DayCountFraction<Self> {
+        DayCountFraction::from_ratio(i64::from(self.adjusted_dates(start, end).days()), 360)
+    }
 
-        let mut m2 = m2 as i32;
-        let mut d2 = d2 as i32;
+    fn day_count<T: DayCountDate>(&self, start: &T, end: &T) -> i64 {
+        i64::from(self.adjusted_dates(start, end).days())
+    }
 
-        if d1 == 31 {
-            d1 = 30;
-        }
-        if d2 == 31 {
-            d2 = 1;
-            m2 += 1;
-        }
+    fn days_in_basis<T: DayCountDate>(&self, _start: &T, _end: &T) -> Option<f64> {
+        Some(360.0)
+    }
 
-        let numerator = (360 * (y2 - y1)) + (30 * (m2 - m1)) + (d2 - d1);
-        DayCountFraction::new(f64::from(numerator) / 360.0)
+    fn date_from_fraction<T: DayCountDate>(&self, start: &T, fraction: f64) -> Option<T> {
+        let days = estimate_days(fraction, 360.0);
+        date_from_days(self, start, fraction, estimate_thirty_days(start, days), 0)
     }
 }
 
@@ -307,3 +586,105 @@ impl std::fmt::Display for ThirtyEPlus360ISDA {
         write!(f, "30E+/360 (ISDA)")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AdjustedDates, DayCountDate, DayCountFraction, DayCounter, Thirty360, Thirty360German,
+        Thirty360ICMA, Thirty360US, Thirty365, ThirtyDayCounter, ThirtyEPlus360ISDA,
+    };
+
+    fn thirty_360_us_end_of_month<T: DayCountDate>() {
+        let day1 = T::from_ymd(2007, 2, 28);
+        let day2 = T::from_ymd(2007, 8, 31);
+        let day3 = T::from_ymd(2008, 2, 29);
+
+        let eom = Thirty360US::new(true);
+        let non_eom = Thirty360US::new(false);
+
+        assert!((eom.day_count_fraction(&day1, &day2).get_fraction() - 0.5).abs() < 1e-12);
+        assert!(
+            (non_eom.day_count_fraction(&day1, &day2).get_fraction() - 183.0 / 360.0).abs() < 1e-12
+        );
+        assert!((eom.day_count_fraction(&day1, &day3).get_fraction() - 1.0).abs() < 1e-12);
+
+        let dates = eom.adjusted_dates(&day1, &day2);
+        assert_eq!((dates.d1, dates.d2), (30, 30));
+        assert_eq!(eom.day_count(&day1, &day2), 180);
+        assert_eq!(non_eom.day_count(&day1, &day2), 183);
+        assert_eq!(eom.days_in_basis(&day1, &day2), Some(360.0));
+    }
+
+    fn thirty_e_plus_360_isda_adjusted_dates<T: DayCountDate>() {
+        let day1 = T::from_ymd(2024, 1, 31);
+        let day2 = T::from_ymd(2024, 3, 31);
+
+        assert_eq!(
+            ThirtyEPlus360ISDA.adjusted_dates(&day1, &day2),
+            AdjustedDates {
+                y1: 2024,
+                m1: 1,
+                d1: 30,
+                y2: 2024,
+                m2: 4,
+                d2: 1,
+            }
+        );
+        assert_eq!(ThirtyEPlus360ISDA.day_count(&day1, &day2), 61);
+    }
+
+    fn thirty_360_icma_end_of_month<T: DayCountDate>() {
+        let day1 = T::from_ymd(2024, 1, 15);
+        let day2 = T::from_ymd(2024, 3, 31);
+
+        // D2 is 31 and is changed to 30, even though D1 is below 30.
+        let dates = Thirty360ICMA.adjusted_dates(&day1, &day2);
+        assert_eq!((dates.d1, dates.d2), (15, 30));
+        assert_eq!(
+            Thirty360ICMA.day_count_fraction(&day1, &day2),
+            DayCountFraction::from_ratio(75, 360)
+        );
+        assert_eq!(Thirty360.day_count(&day1, &day2), 76);
+    }
+
+    fn thirty_365<T: DayCountDate>() {
+        let day1 = T::from_ymd(2024, 1, 31);
+        let day2 = T::from_ymd(2024, 3, 31);
+
+        assert_eq!(Thirty365.day_count(&day1, &day2), 60);
+        assert_eq!(
+            Thirty365.day_count_fraction(&day1, &day2),
+            DayCountFraction::from_ratio(60, 365)
+        );
+        assert_eq!(Thirty365.days_in_basis(&day1, &day2), Some(365.0));
+    }
+
+    fn thirty_360_german_maturity_date<T: DayCountDate>() {
+        let day1 = T::from_ymd(2007, 8, 31);
+        let day2 = T::from_ymd(2008, 2, 29);
+
+        let at_maturity = Thirty360German::new(day2);
+        let before_maturity = Thirty360German::default();
+
+        assert!(
+            (at_maturity.day_count_fraction(&day1, &day2).get_fraction() - 179.0 / 360.0).abs()
+                < 1e-12
+        );
+        assert!(
+            (before_maturity
+                .day_count_fraction(&day1, &day2)
+                .get_fraction()
+                - 0.5)
+                .abs()
+                < 1e-12
+        );
+    }
+
+    test_date_backends!(
+        thirty_360_us_end_of_month,
+        thirty_360_icma_end_of_month,
+        thirty_365,
+        thirty_360_german_maturity_date,
+        thirty_e_plus_360_isda_adjusted_dates
+    );
+}
@@ -1,57 +1,29 @@
-#[cfg(not(feature = "hifitime"))]
-use chrono::{Datelike, NaiveDate};
-#[cfg(feature = "hifitime")]
-use hifitime::{Epoch, Unit};
-
-#[cfg(not(feature = "hifitime"))]
-pub fn get_last_day_of_month(year: i32, month: u32) -> i32 {
-    let next_month = if month == 12 {
-        NaiveDate::from_ymd_opt(year + 1, 1, 1).unwrap()
-    } else {
-        NaiveDate::from_ymd_opt(year, month + 1, 1).unwrap()
-    };
+use crate::{CivilDate, DayCountDate, DayCountFraction, DayCounter};
 
-    next_month.pred_opt().unwrap().day() as i32
-}
-#[cfg(feature = "hifitime")]
-pub fn get_last_day_of_month(year: i32, month: u32) -> i32 {
-    let (next_year, next_month) = if month == 12 {
-        (year + 1, 1)
-    } else {
-        (year, month + 1)
-    };
-    #[allow(clippy::cast_possible_truncation)]
-    let next_month_epoch = Epoch::from_gregorian_utc_at_midnight(next_year, next_month as u8, 1);
-    let last_day_epoch = next_month_epoch - Unit::Day;
-    let (_, _, last_day, _, _, _, _) = last_day_epoch.to_gregorian_utc();
-    last_day.into()
+pub const fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
 }
 
-#[cfg(not(feature = "hifitime"))]
-pub fn is_last_day_of_feb(date: NaiveDate) -> bool {
-    if date.month() == 2 {
-        get_last_day_of_month(date.year(), date.month()) == date.day() as i32
-    } else {
-        false
+pub const fn get_last_day_of_month(year: i32, month: u32) -> i32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
     }
 }
-#[cfg(feature = "hifitime")]
-pub fn is_last_day_of_feb(date: Epoch) -> bool {
-    let (year, month, day, _, _, _, _) = date.to_gregorian_utc();
-    if month == 2 {
-        get_last_day_of_month(year, u32::from(month)) == i32::from(day)
-    } else {
-        false
-    }
+
+pub fn is_last_day_of_feb<T: DayCountDate>(date: T) -> bool {
+    date.month() == 2 && date.is_last_day_of_month()
 }
 
-#[cfg(not(feature = "hifitime"))]
-pub fn is_feb29_between_exc_inc(date1: NaiveDate, date2: NaiveDate) -> bool {
+pub fn is_feb29_between_exc_inc<T: DayCountDate>(date1: T, date2: T) -> bool {
+    let date1 = date1.to_civil();
+    let date2 = date2.to_civil();
     let mut current_year = date1.year();
 
     while current_year <= date2.year() {
-        if NaiveDate::from_ymd_opt(current_year, 2, 29).is_some() {
-            let feb_29 = NaiveDate::from_ymd_opt(current_year, 2, 29).unwrap();
+        if let Some(feb_29) = CivilDate::from_ymd_opt(current_year, 2, 29) {
             if feb_29 > date1 && feb_29 <= date2 {
                 return true;
             }
@@ -60,18 +32,178 @@ pub fn is_feb29_between_exc_inc(date1: NaiveDate, date2: NaiveDate) -> bool {
     }
     false
 }
-#[cfg(feature = "hifitime")]
-pub fn is_feb29_between_exc_inc(date1: Epoch, date2: Epoch) -> bool {
-    let (mut current_year, _, _, _, _, _, _) = date1.to_gregorian_utc();
-    let (end_year, _, _, _, _, _, _) = date1.to_gregorian_utc();
 
-    while current_year <= end_year {
-        if let Ok(feb_29) = Epoch::maybe_from_gregorian_utc(current_year, 2, 29, 0, 0, 0, 0) {
-            if feb_29 > date1 && feb_29 <= date2 {
-                return true;
+pub const fn get_days_in_year(year: i32) -> i32 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+pub fn add_months(date: CivilDate, months: i32) -> CivilDate {
+    let total_months = date.year() * 12 + date.month() as i32 - 1 + months;
+    let year = total_months.div_euclid(12);
+    let month = total_months.rem_euclid(12) as u32 + 1;
+    let day = date.day().min(get_last_day_of_month(year, month) as u32);
+
+    CivilDate::from_ymd(year, month, day)
+}
+
+pub fn actual_days<T: DayCountDate>(start: &T, end: &T) -> i64 {
+    end.to_civil().num_days_from_unix_epoch() - start.to_civil().num_days_from_unix_epoch()
+}
+
+/// The fraction whose numerator is the actual number of days between the two
+/// dates plus `offset`, and whose denominator is `basis.0 / basis.1` days. It
+/// is exact unless the two dates have different times of day.
+#[allow(clippy::cast_precision_loss)]
+pub fn actual_fraction<D: DayCounter, T: DayCountDate>(
+    start: &T,
+    end: &T,
+    offset: i64,
+    basis: (i64, i64),
+) -> DayCountFraction<D> {
+    let days = actual_days(start, end);
+    let elapsed = start.days_until(end);
+    if elapsed == days as f64 {
+        DayCountFraction::from_ratio((days + offset) * basis.1, basis.0)
+    } else {
+        DayCountFraction::new((elapsed + offset as f64) * basis.1 as f64 / basis.0 as f64)
+    }
+}
+
+/// The number of days searched by [`solve_date`] before giving up, which is
+/// about 1,000 years.
+const MAX_SOLVE_DAYS: i64 = 366_000;
+
+/// The number of days in `fraction` of a year of `days_in_year` days, rounded
+/// up.
+#[allow(clippy::cast_possible_truncation)]
+pub fn estimate_days(fraction: f64, days_in_year: f64) -> i64 {
+    (fraction * days_in_year)
+        .ceil()
+        .clamp(0.0, MAX_SOLVE_DAYS as f64) as i64
+}
+
+/// The actual number of days from `start` to the date `days` 30/360 days
+/// later, counting every month as 30 days.
+pub fn estimate_thirty_days<T: DayCountDate>(start: &T, days: i64) -> i64 {
+    let days = days.clamp(0, MAX_SOLVE_DAYS);
+    let mut months = (days / 30) as i32;
+    let mut day = i64::from(start.day().min(30)) + days % 30;
+    if day > 30 {
+        months += 1;
+        day -= 30;
+    }
+    let month = add_months(CivilDate::from_ymd(start.year(), start.month(), 1), months);
+    let last_day = i64::from(get_last_day_of_month(month.year(), month.month()));
+    let end = CivilDate::from_ymd(month.year(), month.month(), day.min(last_day) as u32);
+    actual_days(&start.to_civil(), &end)
+}
+
+/// The date `days` days after `start`, which is `start` itself for zero days.
+fn date_after<T: DayCountDate>(start: &T, days: i64) -> T {
+    if days == 0 {
+        *start
+    } else {
+        let date = start.to_civil().add_days(days);
+        T::from_ymd(date.year(), date.month(), date.day())
+    }
+}
+
+/// Whether the day-count-fraction from `start` to the date `days` days later
+/// reaches `fraction`.
+fn reaches<D: DayCounter, T: DayCountDate>(
+    day_counter: &D,
+    start: &T,
+    fraction: f64,
+    days: i64,
+) -> bool {
+    day_counter
+        .day_count_fraction(start, &date_after(start, days))
+        .get_fraction()
+        >= fraction
+}
+
+/// The earliest date at or after `start` whose day-count-fraction from
+/// `start` reaches `fraction`, given the number of days after `start` at
+/// which it is computed to be reached. The date is checked against the day
+/// counter, and moved by single days to correct the rounding of the
+/// fraction, without going below `min_days` days.
+pub fn date_from_days<D: DayCounter, T: DayCountDate>(
+    day_counter: &D,
+    start: &T,
+    fraction: f64,
+    days: i64,
+    min_days: i64,
+) -> Option<T> {
+    if !fraction.is_finite() || days >= MAX_SOLVE_DAYS {
+        return None;
+    }
+    let mut days = days.max(min_days);
+    while days > min_days && reaches(day_counter, start, fraction, days - 1) {
+        days -= 1;
+    }
+    while !reaches(day_counter, start, fraction, days) {
+        if days >= MAX_SOLVE_DAYS {
+            return None;
+        }
+        days += 1;
+    }
+    Some(date_after(start, days))
+}
+
+/// The earliest date at or after `start` whose day-count-fraction from
+/// `start` reaches `fraction`, searching from the date `estimate` days after
+/// `start`. The fraction is assumed not to decrease as the end date moves
+/// later.
+pub fn solve_date<D: DayCounter, T: DayCountDate>(
+    day_counter: &D,
+    start: &T,
+    fraction: f64,
+    estimate: i64,
+) -> Option<T> {
+    if !fraction.is_finite() {
+        return None;
+    }
+
+    let reaches = |days: i64| reaches(day_counter, start, fraction, days);
+    if reaches(0) {
+        return Some(*start);
+    }
+
+    // Bracket the solution between `low` days, which do not reach the
+    // fraction, and `high` days, which do.
+    let estimate = estimate.clamp(1, MAX_SOLVE_DAYS);
+    let (mut low, mut high) = (estimate - 1, estimate);
+    let mut step = 1;
+    if reaches(estimate) {
+        while low > 0 && reaches(low) {
+            high = low;
+            step *= 2;
+            low = (high - step).max(0);
+        }
+    } else {
+        low = estimate;
+        high = estimate + 1;
+        while !reaches(high) {
+            if high >= MAX_SOLVE_DAYS {
+                return None;
             }
+            low = high;
+            step *= 2;
+            high = (low + step).min(MAX_SOLVE_DAYS);
         }
-        current_year += 1;
     }
-    false
+
+    while high - low > 1 {
+        let middle = low + (high - low) / 2;
+        if reaches(middle) {
+            high = middle;
+        } else {
+            low = middle;
+        }
+    }
+    Some(date_after(start, high))
 }